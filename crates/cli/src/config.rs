@@ -1,12 +1,25 @@
+use client::{
+    auth::{Token, TokenKind},
+    client::HttpClientBuilder,
+    gateway::{GatewayOptions, Intents},
+};
 use dotenvy::dotenv;
 use serde::{Deserialize, Serialize};
-use std::fs;
+use std::{fs, path::PathBuf, time::Duration};
 
 use crate::errors::ConfigError;
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct AuthConfig {
     pub token: String,
+    #[serde(default)]
+    pub kind: TokenKind,
+}
+
+impl AuthConfig {
+    pub fn token(&self) -> Token {
+        Token::new(self.kind, self.token.clone())
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -20,11 +33,80 @@ pub struct CacheConfig {
     pub max_messages: usize,
 }
 
+/// Connection settings for the HTTP client. Timeouts are in seconds, and 0
+/// disables a timeout; unset values keep the client's defaults.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    pub connect_timeout: Option<u64>,
+    pub read_timeout: Option<u64>,
+    pub timeout: Option<u64>,
+    pub proxy: Option<String>,
+    pub ca_certificates: Vec<PathBuf>,
+    pub user_agent: Option<String>,
+}
+
+impl NetworkConfig {
+    pub fn apply(&self, mut builder: HttpClientBuilder) -> Result<HttpClientBuilder, ConfigError> {
+        let seconds = |secs: u64| (secs > 0).then(|| Duration::from_secs(secs));
+
+        if let Some(secs) = self.connect_timeout {
+            builder = builder.connect_timeout(seconds(secs));
+        }
+
+        if let Some(secs) = self.read_timeout {
+            builder = builder.read_timeout(seconds(secs));
+        }
+
+        if let Some(secs) = self.timeout {
+            builder = builder.timeout(seconds(secs));
+        }
+
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+
+        if let Some(user_agent) = &self.user_agent {
+            builder = builder.user_agent(user_agent.clone());
+        }
+
+        for path in &self.ca_certificates {
+            let pem = fs::read(path).map_err(|source| ConfigError::Certificate {
+                path: path.clone(),
+                source,
+            })?;
+            builder = builder.root_certificate_pem(pem);
+        }
+
+        Ok(builder)
+    }
+}
+
+/// Gateway settings. `intents` lists event groups by name, joined by `|`,
+/// e.g. `"GUILDS | GUILD_MESSAGES | MESSAGE_CONTENT"`. Unset, only the
+/// non-privileged intents are requested; privileged ones such as
+/// `MESSAGE_CONTENT` must be listed and enabled in the Developer Portal.
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct GatewayConfig {
+    pub intents: Intents,
+}
+
+impl GatewayConfig {
+    pub fn options(&self) -> GatewayOptions {
+        GatewayOptions::default().with_intents(self.intents)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Config {
     pub auth: AuthConfig,
     pub ui: UiConfig,
     pub cache: CacheConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub gateway: GatewayConfig,
 }
 
 impl Default for Config {
@@ -32,17 +114,33 @@ impl Default for Config {
         Config {
             auth: AuthConfig {
                 token: String::new(),
+                kind: TokenKind::Bot,
             },
             ui: UiConfig {
                 theme: "default".to_string(),
                 vim_mode: false,
             },
             cache: CacheConfig { max_messages: 100 },
+            network: NetworkConfig::default(),
+            gateway: GatewayConfig::default(),
         }
     }
 }
 
+/// Loads the configuration and requires a Discord token to be present.
 pub fn load_config() -> Result<Config, ConfigError> {
+    let config = read_config()?;
+
+    if config.auth.token.is_empty() {
+        return Err(ConfigError::MissingToken);
+    }
+
+    Ok(config)
+}
+
+/// Loads the configuration without requiring a token, for commands such as
+/// `webhook` that authenticate another way.
+pub fn read_config() -> Result<Config, ConfigError> {
     let _ = dotenv();
 
     let config_path = dirs::config_dir()
@@ -62,10 +160,6 @@ pub fn load_config() -> Result<Config, ConfigError> {
         config.auth.token = token;
     }
 
-    if config.auth.token.is_empty() {
-        return Err(ConfigError::MissingToken);
-    }
-
     Ok(config)
 }
 
@@ -89,12 +183,106 @@ mod tests {
 
         let config: Config = toml::from_str(toml_str).unwrap();
         assert_eq!(config.auth.token, "test-token");
+        assert_eq!(config.auth.kind, TokenKind::Bot);
         assert_eq!(config.ui.theme, "dark");
         assert!(config.ui.vim_mode);
         assert_eq!(config.cache.max_messages, 50);
+        assert!(config.network.proxy.is_none());
+        assert_eq!(config.gateway.intents, Intents::default());
+    }
+
+    #[test]
+    fn test_config_gateway_intents() {
+        let toml_str = r#"
+            [auth]
+            token = "test-token"
+
+            [ui]
+            theme = "dark"
+            vim_mode = false
+
+            [cache]
+            max_messages = 50
+
+            [gateway]
+            intents = "GUILDS | GUILD_MESSAGES | MESSAGE_CONTENT"
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.gateway.intents,
+            Intents::GUILDS | Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT
+        );
+        assert_eq!(config.gateway.options().intents, config.gateway.intents);
+    }
+
+    #[test]
+    fn test_config_network_section() {
+        let toml_str = r#"
+            [auth]
+            token = "test-token"
+
+            [ui]
+            theme = "dark"
+            vim_mode = false
+
+            [cache]
+            max_messages = 50
+
+            [network]
+            connect_timeout = 5
+            timeout = 0
+            proxy = "socks5h://proxy.internal:1080"
+            ca_certificates = ["/etc/ssl/corp-root.pem"]
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.network.connect_timeout, Some(5));
+        assert_eq!(config.network.timeout, Some(0));
+        assert_eq!(config.network.read_timeout, None);
+        assert_eq!(
+            config.network.proxy.as_deref(),
+            Some("socks5h://proxy.internal:1080")
+        );
+        assert_eq!(
+            config.network.ca_certificates,
+            vec![PathBuf::from("/etc/ssl/corp-root.pem")]
+        );
+    }
+
+    #[test]
+    fn test_network_missing_certificate() {
+        let network = NetworkConfig {
+            ca_certificates: vec![PathBuf::from("/nonexistent/discline-ca.pem")],
+            ..Default::default()
+        };
+
+        let result = network.apply(HttpClientBuilder::new(Token::bot("test-token")));
+
+        assert!(matches!(result, Err(ConfigError::Certificate { .. })));
+    }
+
+    #[test]
+    fn test_config_bearer_token() {
+        let toml_str = r#"
+            [auth]
+            token = "oauth-token"
+            kind = "bearer"
+
+            [ui]
+            theme = "dark"
+            vim_mode = false
+
+            [cache]
+            max_messages = 50
+        "#;
+
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.auth.token().authorization(), "Bearer oauth-token");
     }
 
     #[test]
+    #[allow(clippy::collapsible_if)]
     fn test_token_precedence_file_wins() {
         let mut config = Config::default();
         config.auth.token = "file-token".to_string();
@@ -112,6 +300,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::collapsible_if)]
     fn test_token_precedence_env_fallback() {
         let mut config = Config::default();
         config.auth.token = "".to_string();
@@ -0,0 +1,101 @@
+use client::bodies::CreateCommand;
+use serde::Deserialize;
+use std::{fs, path::Path};
+
+use crate::errors::DefinitionsError;
+
+/// A file of command definitions: a `commands` table array in TOML, and in
+/// JSON either the same object or a bare array as Discord's API takes it.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum DefinitionsFile {
+    Table { commands: Vec<CreateCommand> },
+    List(Vec<CreateCommand>),
+}
+
+impl From<DefinitionsFile> for Vec<CreateCommand> {
+    fn from(file: DefinitionsFile) -> Self {
+        match file {
+            DefinitionsFile::Table { commands } | DefinitionsFile::List(commands) => commands,
+        }
+    }
+}
+
+/// Reads command definitions, choosing the format from the file extension.
+pub fn read_command_definitions(path: &Path) -> Result<Vec<CreateCommand>, DefinitionsError> {
+    let parse = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => parse_toml,
+        Some("json") => parse_json,
+        _ => return Err(DefinitionsError::UnknownFormat(path.to_path_buf())),
+    };
+
+    parse(&fs::read_to_string(path)?)
+}
+
+fn parse_toml(contents: &str) -> Result<Vec<CreateCommand>, DefinitionsError> {
+    Ok(toml::from_str::<DefinitionsFile>(contents)?.into())
+}
+
+fn parse_json(contents: &str) -> Result<Vec<CreateCommand>, DefinitionsError> {
+    Ok(serde_json::from_str::<DefinitionsFile>(contents)?.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use types::command::{CommandOptionType, CommandOptionValue};
+
+    #[test]
+    fn test_parse_toml_definitions() {
+        let commands = parse_toml(
+            r#"
+            [[commands]]
+            name = "deploy"
+            description = "Deploy a service"
+
+            [[commands.options]]
+            type = 3
+            name = "env"
+            description = "Target environment"
+            required = true
+            choices = [
+                { name = "Production", value = "prod" },
+                { name = "Staging", value = "staging" },
+            ]
+
+            [[commands]]
+            name = "ping"
+            description = "Check latency"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].options[0].kind, CommandOptionType::String);
+        assert_eq!(
+            commands[0].options[0].choices[1].value,
+            CommandOptionValue::String("staging".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_json_array_definitions() {
+        let commands = parse_json(
+            r#"[
+                { "name": "ping", "description": "Check latency" },
+                { "name": "Show Profile", "type": 2 }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(commands.len(), 2);
+        assert!(commands.iter().all(|c| c.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_unknown_extension_is_rejected() {
+        let result = read_command_definitions(Path::new("commands.yaml"));
+
+        assert!(matches!(result, Err(DefinitionsError::UnknownFormat(_))));
+    }
+}
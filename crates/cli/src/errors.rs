@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -13,4 +14,25 @@ pub enum ConfigError {
 
     #[error("Failed to determine config directory")]
     NoConfigDir,
+
+    #[error("Failed to read CA certificate {}: {source}", path.display())]
+    Certificate {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+#[derive(Error, Debug)]
+pub enum DefinitionsError {
+    #[error("Failed to read command definitions: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse TOML command definitions: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Failed to parse JSON command definitions: {0}")]
+    Json(#[from] serde_json::Error),
+
+    #[error("Unsupported command definitions file {}: expected .toml or .json", .0.display())]
+    UnknownFormat(PathBuf),
 }
@@ -1,13 +1,315 @@
-use crate::config::load_config;
+use crate::{
+    config::{load_config, read_config},
+    definitions::read_command_definitions,
+};
 use anyhow::{Context, Result};
-use client::client::HttpClient;
+use clap::{Parser, Subcommand};
+use client::{
+    client::{HttpClient, HttpClientBuilder},
+    gateway::GatewaySupervisor,
+    webhook::WebhookClient,
+};
+use commands::{
+    application_commands::execute_commands_sync,
+    webhook::{WebhookMessage, execute_webhook_delete, execute_webhook_edit, execute_webhook_send},
+};
+use std::path::PathBuf;
+use types::{channel::ChannelId, command::ApplicationId, guild::GuildId, message::MessageId};
 
 pub mod config;
+pub mod definitions;
 pub mod errors;
 
-fn main() -> Result<()> {
-    let config = load_config().context("Failed to initialize application configuration")?;
-    let _client = HttpClient::new(config.auth.token);
+#[derive(Parser)]
+#[command(name = "discline", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Send, edit or delete messages through a webhook, without a bot token
+    Webhook {
+        /// Webhook URL, e.g. https://discord.com/api/webhooks/{id}/{token}
+        #[arg(long, env = "DISCORD_WEBHOOK_URL", hide_env_values = true)]
+        url: String,
+
+        #[command(subcommand)]
+        action: WebhookAction,
+    },
+    /// Manage the bot's application (slash) commands
+    Commands {
+        #[command(subcommand)]
+        action: CommandsAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum CommandsAction {
+    /// Replace the registered commands with the definitions in a TOML or JSON file
+    Sync {
+        /// Command definitions (.toml or .json)
+        file: PathBuf,
+
+        /// The bot's application ID
+        #[arg(long, env = "DISCORD_APPLICATION_ID")]
+        application_id: u64,
+
+        /// Register the commands in this guild only instead of globally
+        #[arg(long)]
+        guild_id: Option<u64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WebhookAction {
+    /// Post a new message
+    Send {
+        /// Message text
+        #[arg(required_unless_present_any = ["embed_title", "embed_description"])]
+        content: Option<String>,
+
+        /// Override the webhook's username
+        #[arg(long)]
+        username: Option<String>,
+
+        /// Override the webhook's avatar
+        #[arg(long)]
+        avatar_url: Option<String>,
+
+        /// Post in this thread of the webhook's channel
+        #[arg(long)]
+        thread_id: Option<u64>,
+
+        #[arg(long)]
+        embed_title: Option<String>,
+
+        #[arg(long)]
+        embed_description: Option<String>,
+
+        /// Embed color as hex (#2ecc71) or decimal
+        #[arg(long, value_parser = parse_color)]
+        embed_color: Option<u32>,
+    },
+    /// Replace the text of a message the webhook sent
+    Edit {
+        message_id: u64,
+        content: String,
+
+        #[arg(long)]
+        thread_id: Option<u64>,
+    },
+    /// Delete a message the webhook sent
+    Delete {
+        message_id: u64,
+
+        #[arg(long)]
+        thread_id: Option<u64>,
+    },
+}
+
+fn parse_color(value: &str) -> Result<u32, String> {
+    let parsed = match value.strip_prefix('#').or_else(|| value.strip_prefix("0x")) {
+        Some(hex) => u32::from_str_radix(hex, 16),
+        None => value.parse::<u32>(),
+    };
+
+    parsed
+        .ok()
+        .filter(|color| *color <= 0xFFFFFF)
+        .ok_or_else(|| format!("invalid color: {}", value))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Webhook { url, action }) => run_webhook(&url, action).await,
+        Some(Command::Commands { action }) => run_commands(action).await,
+        None => {
+            let config = load_config().context("Failed to initialize application configuration")?;
+            let _client = config
+                .network
+                .apply(HttpClient::builder(config.auth.token()))?
+                .build()
+                .context("Failed to create HTTP client")?;
+            let _gateway =
+                GatewaySupervisor::with_options(config.auth.token(), config.gateway.options());
+
+            Ok(())
+        }
+    }
+}
+
+async fn run_webhook(url: &str, action: WebhookAction) -> Result<()> {
+    let config = read_config().context("Failed to initialize application configuration")?;
+    let http = config
+        .network
+        .apply(HttpClientBuilder::unauthenticated())?
+        .build()
+        .context("Failed to create HTTP client")?;
+    let client = WebhookClient::with_http(http, url)?;
+
+    match action {
+        WebhookAction::Send {
+            content,
+            username,
+            avatar_url,
+            thread_id,
+            embed_title,
+            embed_description,
+            embed_color,
+        } => {
+            let message = WebhookMessage {
+                content,
+                username,
+                avatar_url,
+                embed_title,
+                embed_description,
+                embed_color,
+                thread_id: thread_id.map(ChannelId),
+            };
+
+            execute_webhook_send(&client, &message)
+                .await
+                .context("Failed to send webhook message")?;
+        }
+        WebhookAction::Edit {
+            message_id,
+            content,
+            thread_id,
+        } => {
+            execute_webhook_edit(
+                &client,
+                MessageId(message_id),
+                &content,
+                thread_id.map(ChannelId),
+            )
+            .await
+            .context("Failed to edit webhook message")?;
+        }
+        WebhookAction::Delete {
+            message_id,
+            thread_id,
+        } => {
+            execute_webhook_delete(&client, MessageId(message_id), thread_id.map(ChannelId))
+                .await
+                .context("Failed to delete webhook message")?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_commands(action: CommandsAction) -> Result<()> {
+    match action {
+        CommandsAction::Sync {
+            file,
+            application_id,
+            guild_id,
+        } => {
+            let definitions = read_command_definitions(&file)?;
+
+            let config = load_config().context("Failed to initialize application configuration")?;
+            let client = config
+                .network
+                .apply(HttpClient::builder(config.auth.token()))?
+                .build()
+                .context("Failed to create HTTP client")?;
+
+            execute_commands_sync(
+                &client,
+                ApplicationId(application_id),
+                guild_id.map(GuildId),
+                &definitions,
+            )
+            .await
+            .context("Failed to sync application commands")?;
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli_definition() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("#2ecc71"), Ok(0x2ecc71));
+        assert_eq!(parse_color("0xFF0000"), Ok(0xFF0000));
+        assert_eq!(parse_color("255"), Ok(255));
+        assert!(parse_color("#1000000").is_err());
+        assert!(parse_color("green").is_err());
+    }
+
+    #[test]
+    fn test_webhook_send_requires_content_or_embed() {
+        let result = Cli::try_parse_from([
+            "discline",
+            "webhook",
+            "--url",
+            "https://discord.com/api/webhooks/1/token",
+            "send",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_webhook_send_with_embed_only() {
+        let cli = Cli::try_parse_from([
+            "discline",
+            "webhook",
+            "--url",
+            "https://discord.com/api/webhooks/1/token",
+            "send",
+            "--embed-title",
+            "Deploy finished",
+        ])
+        .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Some(Command::Webhook {
+                action: WebhookAction::Send { content: None, .. },
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_commands_sync_arguments() {
+        let cli = Cli::try_parse_from([
+            "discline",
+            "commands",
+            "sync",
+            "commands.toml",
+            "--application-id",
+            "5",
+            "--guild-id",
+            "10",
+        ])
+        .unwrap();
+
+        assert!(matches!(
+            cli.command,
+            Some(Command::Commands {
+                action: CommandsAction::Sync {
+                    application_id: 5,
+                    guild_id: Some(10),
+                    ..
+                }
+            })
+        ));
+    }
+}
@@ -0,0 +1,118 @@
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::errors::client::ClientError;
+
+/// How a token authenticates with Discord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenKind {
+    #[default]
+    Bot,
+    Bearer,
+}
+
+impl TokenKind {
+    fn prefix(self) -> &'static str {
+        match self {
+            TokenKind::Bot => "Bot",
+            TokenKind::Bearer => "Bearer",
+        }
+    }
+}
+
+/// A Discord token together with its kind, shared by `HttpClient` and
+/// `Gateway` so both authenticate the same way.
+///
+/// The secret is redacted from `Debug` output.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Token {
+    kind: TokenKind,
+    secret: String,
+}
+
+impl Token {
+    /// Creates a token, dropping a leading `Bot ` or `Bearer ` prefix if the
+    /// secret was copied together with it.
+    pub fn new(kind: TokenKind, secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        let secret = secret
+            .strip_prefix(&format!("{} ", kind.prefix()))
+            .map(str::to_string)
+            .unwrap_or(secret);
+
+        Self { kind, secret }
+    }
+
+    pub fn bot(secret: impl Into<String>) -> Self {
+        Self::new(TokenKind::Bot, secret)
+    }
+
+    pub fn bearer(secret: impl Into<String>) -> Self {
+        Self::new(TokenKind::Bearer, secret)
+    }
+
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    pub fn secret(&self) -> &str {
+        &self.secret
+    }
+
+    /// The value sent in the `Authorization` header of REST requests.
+    pub fn authorization(&self) -> String {
+        format!("{} {}", self.kind.prefix(), self.secret)
+    }
+
+    pub(crate) fn header_value(&self) -> Result<HeaderValue, ClientError> {
+        let mut value =
+            HeaderValue::from_str(&self.authorization()).map_err(|_| ClientError::InvalidToken)?;
+        value.set_sensitive(true);
+
+        Ok(value)
+    }
+}
+
+impl fmt::Debug for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Token")
+            .field("kind", &self.kind)
+            .field("secret", &"[redacted]")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_authorization() {
+        assert_eq!(Token::bot("abc").authorization(), "Bot abc");
+        assert_eq!(Token::bearer("abc").authorization(), "Bearer abc");
+    }
+
+    #[test]
+    fn test_token_strips_pasted_prefix() {
+        let token = Token::bot("Bot abc");
+
+        assert_eq!(token.secret(), "abc");
+        assert_eq!(token.authorization(), "Bot abc");
+    }
+
+    #[test]
+    fn test_token_debug_is_redacted() {
+        let debug = format!("{:?}", Token::bot("super-secret"));
+
+        assert!(!debug.contains("super-secret"));
+    }
+
+    #[test]
+    fn test_token_invalid_header_characters() {
+        let result = Token::bot("bad\ntoken").header_value();
+
+        assert!(matches!(result, Err(ClientError::InvalidToken)));
+    }
+}
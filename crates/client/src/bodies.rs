@@ -0,0 +1,931 @@
+use serde::{Deserialize, Serialize};
+use types::{
+    channel::ChannelType,
+    command::{CommandOption, CommandOptionChoice, CommandOptionType, CommandType},
+    component::Component,
+    embed::Embed,
+    guild::RoleId,
+    message::{MessageId, MessageReference},
+    user::UserId,
+};
+
+use crate::errors::client::ClientError;
+
+const MAX_MESSAGE_LENGTH: usize = 2000;
+
+const MAX_EMBEDS: usize = 10;
+const MAX_EMBED_TOTAL_LENGTH: usize = 6000;
+const MAX_EMBED_FIELDS: usize = 25;
+const MAX_EMBED_TITLE_LENGTH: usize = 256;
+const MAX_EMBED_DESCRIPTION_LENGTH: usize = 4096;
+const MAX_EMBED_FIELD_NAME_LENGTH: usize = 256;
+const MAX_EMBED_FIELD_VALUE_LENGTH: usize = 1024;
+const MAX_EMBED_FOOTER_LENGTH: usize = 2048;
+const MAX_EMBED_AUTHOR_LENGTH: usize = 256;
+
+const MAX_COMMAND_NAME_LENGTH: usize = 32;
+const MAX_COMMAND_DESCRIPTION_LENGTH: usize = 100;
+const MAX_COMMAND_OPTIONS: usize = 25;
+const MAX_COMMAND_CHOICES: usize = 25;
+
+const MAX_MODAL_TITLE_LENGTH: usize = 45;
+const MAX_MODAL_ROWS: usize = 5;
+const MAX_ACTION_ROWS: usize = 5;
+
+/// Only the invoking user can see the message.
+const EPHEMERAL_FLAG: u64 = 1 << 6;
+
+/// The body of a new message.
+///
+/// Mentions of users and roles notify by default, but `@everyone` and `@here`
+/// do not; opt in with `allowed_mentions(AllowedMentions::all())`.
+#[derive(Debug, Serialize, Clone)]
+pub struct CreateMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_reference: Option<MessageReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+}
+
+impl Default for CreateMessage {
+    fn default() -> Self {
+        Self {
+            content: None,
+            embeds: Vec::new(),
+            message_reference: None,
+            allowed_mentions: Some(AllowedMentions::no_everyone()),
+        }
+    }
+}
+
+impl CreateMessage {
+    pub fn with_content(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Makes this message a reply to `message_id`. `ping` controls whether the
+    /// replied-to author is mentioned; the rest of the mention policy is kept.
+    pub fn reply_to(mut self, message_id: MessageId, ping: bool) -> Self {
+        self.message_reference = Some(MessageReference::to_message(message_id));
+        self.allowed_mentions
+            .get_or_insert_with(AllowedMentions::no_everyone)
+            .replied_user = ping;
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_content(self.content.as_deref().unwrap_or_default())?;
+        validate_embeds(&self.embeds)
+    }
+}
+
+/// The body of a message sent through a webhook.
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct ExecuteWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    /// Creates a post with this name when the webhook targets a forum
+    /// channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_name: Option<String>,
+}
+
+impl ExecuteWebhook {
+    pub fn with_content(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Overrides the webhook's default username for this message.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Overrides the webhook's default avatar for this message.
+    pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.avatar_url = Some(avatar_url.into());
+        self
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_content(self.content.as_deref().unwrap_or_default())?;
+        validate_embeds(&self.embeds)
+    }
+}
+
+/// The body of an edit to a message previously sent through a webhook. Only
+/// the fields that are set are changed.
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct EditWebhookMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embeds: Option<Vec<Embed>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
+}
+
+impl EditWebhookMessage {
+    pub fn with_content(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Replaces every embed on the message; an empty list removes them.
+    pub fn embeds(mut self, embeds: Vec<Embed>) -> Self {
+        self.embeds = Some(embeds);
+        self
+    }
+
+    /// Replaces every component on the message; an empty list removes them.
+    pub fn components(mut self, components: Vec<Component>) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_content(self.content.as_deref().unwrap_or_default())?;
+        validate_embeds(self.embeds.as_deref().unwrap_or_default())
+    }
+}
+
+/// A message sent in response to an interaction, either as the initial
+/// response or as a follow-up.
+#[derive(Debug, Serialize, Default, Clone)]
+pub struct InteractionMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub embeds: Vec<Embed>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentions>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+}
+
+impl InteractionMessage {
+    pub fn with_content(content: impl Into<String>) -> Self {
+        Self {
+            content: Some(content.into()),
+            ..Default::default()
+        }
+    }
+
+    pub fn embed(mut self, embed: Embed) -> Self {
+        self.embeds.push(embed);
+        self
+    }
+
+    /// Adds an action row; message components must be wrapped in one.
+    pub fn action_row(mut self, components: Vec<Component>) -> Self {
+        self.components.push(Component::action_row(components));
+        self
+    }
+
+    pub fn allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
+        self.allowed_mentions = Some(allowed_mentions);
+        self
+    }
+
+    /// Shows the message to the invoking user only.
+    pub fn ephemeral(mut self) -> Self {
+        self.flags = Some(self.flags.unwrap_or_default() | EPHEMERAL_FLAG);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        validate_content(self.content.as_deref().unwrap_or_default())?;
+        validate_embeds(&self.embeds)?;
+
+        if self.components.len() > MAX_ACTION_ROWS {
+            return Err(ClientError::InvalidInteractionResponse(format!(
+                "{} action rows, maximum is {}",
+                self.components.len(),
+                MAX_ACTION_ROWS
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// A form shown to the user in response to an interaction. Its text inputs
+/// come back in a modal-submit interaction.
+#[derive(Debug, Serialize, Clone)]
+pub struct Modal {
+    pub custom_id: String,
+    pub title: String,
+    pub components: Vec<Component>,
+}
+
+impl Modal {
+    pub fn new(custom_id: impl Into<String>, title: impl Into<String>) -> Self {
+        Self {
+            custom_id: custom_id.into(),
+            title: title.into(),
+            components: Vec::new(),
+        }
+    }
+
+    /// Adds a text input in its own action row.
+    pub fn text_input(mut self, input: Component) -> Self {
+        self.components.push(Component::action_row(vec![input]));
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(into = "u8")]
+pub enum InteractionCallbackType {
+    Pong,
+    ChannelMessageWithSource,
+    DeferredChannelMessageWithSource,
+    DeferredUpdateMessage,
+    UpdateMessage,
+    AutocompleteResult,
+    Modal,
+}
+
+impl From<InteractionCallbackType> for u8 {
+    fn from(value: InteractionCallbackType) -> Self {
+        match value {
+            InteractionCallbackType::Pong => 1,
+            InteractionCallbackType::ChannelMessageWithSource => 4,
+            InteractionCallbackType::DeferredChannelMessageWithSource => 5,
+            InteractionCallbackType::DeferredUpdateMessage => 6,
+            InteractionCallbackType::UpdateMessage => 7,
+            InteractionCallbackType::AutocompleteResult => 8,
+            InteractionCallbackType::Modal => 9,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(untagged)]
+pub enum InteractionCallbackData {
+    Message(InteractionMessage),
+    Modal(Modal),
+    Autocomplete { choices: Vec<CommandOptionChoice> },
+}
+
+/// The initial response to an interaction. It has to be sent within three
+/// seconds; deferring it extends that to fifteen minutes, after which the
+/// original response is edited with `RestClient::edit_original_response`.
+#[derive(Debug, Serialize, Clone)]
+pub struct InteractionResponse {
+    #[serde(rename = "type")]
+    pub kind: InteractionCallbackType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<InteractionCallbackData>,
+}
+
+impl InteractionResponse {
+    /// Acknowledges a ping sent to an HTTP interactions endpoint.
+    pub fn pong() -> Self {
+        Self {
+            kind: InteractionCallbackType::Pong,
+            data: None,
+        }
+    }
+
+    pub fn message(message: InteractionMessage) -> Self {
+        Self {
+            kind: InteractionCallbackType::ChannelMessageWithSource,
+            data: Some(InteractionCallbackData::Message(message)),
+        }
+    }
+
+    /// Shows a loading state; the message is filled in later by editing the
+    /// original response.
+    pub fn deferred_message(ephemeral: bool) -> Self {
+        let message = InteractionMessage::default();
+
+        Self {
+            kind: InteractionCallbackType::DeferredChannelMessageWithSource,
+            data: ephemeral.then(|| InteractionCallbackData::Message(message.ephemeral())),
+        }
+    }
+
+    /// Acknowledges a component interaction without changing its message yet.
+    pub fn deferred_update() -> Self {
+        Self {
+            kind: InteractionCallbackType::DeferredUpdateMessage,
+            data: None,
+        }
+    }
+
+    /// Edits the message a component is attached to.
+    pub fn update_message(message: InteractionMessage) -> Self {
+        Self {
+            kind: InteractionCallbackType::UpdateMessage,
+            data: Some(InteractionCallbackData::Message(message)),
+        }
+    }
+
+    pub fn autocomplete(choices: Vec<CommandOptionChoice>) -> Self {
+        Self {
+            kind: InteractionCallbackType::AutocompleteResult,
+            data: Some(InteractionCallbackData::Autocomplete { choices }),
+        }
+    }
+
+    pub fn modal(modal: Modal) -> Self {
+        Self {
+            kind: InteractionCallbackType::Modal,
+            data: Some(InteractionCallbackData::Modal(modal)),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ClientError> {
+        let invalid = |reason: String| Err(ClientError::InvalidInteractionResponse(reason));
+
+        match &self.data {
+            Some(InteractionCallbackData::Message(message)) => message.validate(),
+            Some(InteractionCallbackData::Modal(modal)) => {
+                if modal.title.chars().count() > MAX_MODAL_TITLE_LENGTH {
+                    return invalid(format!(
+                        "modal title is longer than {} characters",
+                        MAX_MODAL_TITLE_LENGTH
+                    ));
+                }
+
+                if modal.components.is_empty() || modal.components.len() > MAX_MODAL_ROWS {
+                    return invalid(format!(
+                        "modal needs 1 to {} text inputs, got {}",
+                        MAX_MODAL_ROWS,
+                        modal.components.len()
+                    ));
+                }
+
+                Ok(())
+            }
+            Some(InteractionCallbackData::Autocomplete { choices })
+                if choices.len() > MAX_COMMAND_CHOICES =>
+            {
+                invalid(format!(
+                    "{} autocomplete choices, maximum is {}",
+                    choices.len(),
+                    MAX_COMMAND_CHOICES
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MentionType {
+    Roles,
+    Users,
+    Everyone,
+}
+
+/// Controls which mentions in a message actually notify anyone.
+///
+/// The default value suppresses every mention.
+#[derive(Debug, Serialize, Default, Clone, PartialEq, Eq)]
+pub struct AllowedMentions {
+    pub parse: Vec<MentionType>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<UserId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<RoleId>,
+    pub replied_user: bool,
+}
+
+impl AllowedMentions {
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn all() -> Self {
+        Self {
+            parse: vec![
+                MentionType::Roles,
+                MentionType::Users,
+                MentionType::Everyone,
+            ],
+            replied_user: true,
+            ..Default::default()
+        }
+    }
+
+    /// Allows user and role mentions but never `@everyone` or `@here`.
+    pub fn no_everyone() -> Self {
+        Self {
+            parse: vec![MentionType::Roles, MentionType::Users],
+            replied_user: true,
+            ..Default::default()
+        }
+    }
+}
+
+/// The body used to start a thread, either from an existing message or on
+/// its own.
+#[derive(Debug, Serialize, Clone)]
+pub struct StartThread {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_archive_duration: Option<u32>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<ChannelType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rate_limit_per_user: Option<u32>,
+}
+
+impl StartThread {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            auto_archive_duration: None,
+            kind: None,
+            invitable: None,
+            rate_limit_per_user: None,
+        }
+    }
+
+    /// Makes a thread started without a message private. Only meaningful for
+    /// `RestClient::start_thread`; threads started from a message are public.
+    pub fn private(mut self) -> Self {
+        self.kind = Some(ChannelType::PrivateThread);
+        self
+    }
+}
+
+/// The definition of an application command, used to create one or to
+/// overwrite the whole set. Also deserializable so definitions can be kept in
+/// a file.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateCommand {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(rename = "type", default)]
+    pub kind: CommandType,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_member_permissions: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub nsfw: bool,
+}
+
+impl CreateCommand {
+    /// A slash command.
+    pub fn chat_input(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            kind: CommandType::ChatInput,
+            options: Vec::new(),
+            default_member_permissions: None,
+            nsfw: false,
+        }
+    }
+
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Checks names, descriptions and option counts against Discord's
+    /// documented limits. Context-menu commands take neither a description
+    /// nor options.
+    pub fn validate(&self) -> Result<(), ClientError> {
+        match self.kind {
+            CommandType::ChatInput => {
+                validate_command_name(&self.name, true)?;
+                validate_command_description(&self.name, &self.description)?;
+                validate_command_options(&self.name, &self.options)
+            }
+            _ => {
+                validate_command_name(&self.name, false)?;
+
+                if !self.description.is_empty() || !self.options.is_empty() {
+                    return Err(ClientError::InvalidCommand(format!(
+                        "{}: context menu commands cannot have a description or options",
+                        self.name
+                    )));
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+pub(crate) fn validate_content(content: &str) -> Result<(), ClientError> {
+    if content.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(ClientError::MessageTooLong);
+    }
+
+    Ok(())
+}
+
+/// Checks embeds against Discord's documented size limits.
+pub(crate) fn validate_embeds(embeds: &[Embed]) -> Result<(), ClientError> {
+    let too_long = |what: &str, value: &str, max: usize| {
+        if value.chars().count() > max {
+            Err(ClientError::InvalidEmbed(format!(
+                "{} exceeds {} characters",
+                what, max
+            )))
+        } else {
+            Ok(())
+        }
+    };
+
+    if embeds.len() > MAX_EMBEDS {
+        return Err(ClientError::InvalidEmbed(format!(
+            "a message can have at most {} embeds",
+            MAX_EMBEDS
+        )));
+    }
+
+    for embed in embeds {
+        if let Some(title) = &embed.title {
+            too_long("title", title, MAX_EMBED_TITLE_LENGTH)?;
+        }
+        if let Some(description) = &embed.description {
+            too_long("description", description, MAX_EMBED_DESCRIPTION_LENGTH)?;
+        }
+        if let Some(footer) = &embed.footer {
+            too_long("footer text", &footer.text, MAX_EMBED_FOOTER_LENGTH)?;
+        }
+        if let Some(author) = &embed.author {
+            too_long("author name", &author.name, MAX_EMBED_AUTHOR_LENGTH)?;
+        }
+
+        if embed.fields.len() > MAX_EMBED_FIELDS {
+            return Err(ClientError::InvalidEmbed(format!(
+                "an embed can have at most {} fields",
+                MAX_EMBED_FIELDS
+            )));
+        }
+
+        for field in &embed.fields {
+            too_long("field name", &field.name, MAX_EMBED_FIELD_NAME_LENGTH)?;
+            too_long("field value", &field.value, MAX_EMBED_FIELD_VALUE_LENGTH)?;
+        }
+    }
+
+    let total: usize = embeds.iter().map(Embed::char_count).sum();
+
+    if total > MAX_EMBED_TOTAL_LENGTH {
+        return Err(ClientError::InvalidEmbed(format!(
+            "embeds total {} characters, maximum is {}",
+            total, MAX_EMBED_TOTAL_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+/// Slash command and option names are lowercase words of at most 32
+/// characters; context-menu names may contain spaces and capitals.
+fn validate_command_name(name: &str, chat_input: bool) -> Result<(), ClientError> {
+    let length = name.chars().count();
+
+    if length == 0 || length > MAX_COMMAND_NAME_LENGTH {
+        return Err(ClientError::InvalidCommand(format!(
+            "name {:?} must be 1 to {} characters",
+            name, MAX_COMMAND_NAME_LENGTH
+        )));
+    }
+
+    let valid = |c: char| (c.is_alphanumeric() && !c.is_uppercase()) || c == '-' || c == '_';
+
+    if chat_input && !name.chars().all(valid) {
+        return Err(ClientError::InvalidCommand(format!(
+            "name {:?} may only contain lowercase letters, digits, '-' and '_'",
+            name
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_command_description(name: &str, description: &str) -> Result<(), ClientError> {
+    let length = description.chars().count();
+
+    if length == 0 || length > MAX_COMMAND_DESCRIPTION_LENGTH {
+        return Err(ClientError::InvalidCommand(format!(
+            "{}: description must be 1 to {} characters",
+            name, MAX_COMMAND_DESCRIPTION_LENGTH
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_command_options(parent: &str, options: &[CommandOption]) -> Result<(), ClientError> {
+    if options.len() > MAX_COMMAND_OPTIONS {
+        return Err(ClientError::InvalidCommand(format!(
+            "{}: has {} options, maximum is {}",
+            parent,
+            options.len(),
+            MAX_COMMAND_OPTIONS
+        )));
+    }
+
+    let mut seen_optional = false;
+
+    for option in options {
+        let path = format!("{}.{}", parent, option.name);
+
+        validate_command_name(&option.name, true)?;
+        validate_command_description(&path, &option.description)?;
+
+        if option.choices.len() > MAX_COMMAND_CHOICES {
+            return Err(ClientError::InvalidCommand(format!(
+                "{}: has {} choices, maximum is {}",
+                path,
+                option.choices.len(),
+                MAX_COMMAND_CHOICES
+            )));
+        }
+
+        let nested = matches!(
+            option.kind,
+            CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup
+        );
+
+        if nested {
+            validate_command_options(&path, &option.options)?;
+        } else if option.required && seen_optional {
+            return Err(ClientError::InvalidCommand(format!(
+                "{}: required options must come before optional ones",
+                path
+            )));
+        } else if !option.required {
+            seen_optional = true;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use types::embed::EmbedField;
+
+    fn field(name: &str, value: &str) -> EmbedField {
+        EmbedField {
+            name: name.to_string(),
+            value: value.to_string(),
+            inline: false,
+        }
+    }
+
+    #[test]
+    fn test_reply_without_ping() {
+        let message = CreateMessage::with_content("on it").reply_to(MessageId(7), false);
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({
+                "content": "on it",
+                "message_reference": { "message_id": 7 },
+                "allowed_mentions": {
+                    "parse": ["roles", "users"],
+                    "replied_user": false
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_plain_message_never_pings_everyone() {
+        let message = CreateMessage::with_content("@everyone deploy finished");
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({
+                "content": "@everyone deploy finished",
+                "allowed_mentions": { "parse": ["roles", "users"], "replied_user": true }
+            })
+        );
+    }
+
+    #[test]
+    fn test_reply_never_pings_everyone_by_default() {
+        let message =
+            CreateMessage::with_content("@everyone build is green").reply_to(MessageId(7), true);
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap()["allowed_mentions"],
+            json!({ "parse": ["roles", "users"], "replied_user": true })
+        );
+    }
+
+    #[test]
+    fn test_reply_keeps_existing_mention_policy() {
+        let message = CreateMessage::with_content("deployed")
+            .allowed_mentions(AllowedMentions::none())
+            .reply_to(MessageId(7), true);
+
+        assert_eq!(
+            message.allowed_mentions,
+            Some(AllowedMentions {
+                replied_user: true,
+                ..AllowedMentions::none()
+            })
+        );
+    }
+
+    #[test]
+    fn test_allowed_mentions_none_serializes_empty_parse() {
+        assert_eq!(
+            serde_json::to_value(AllowedMentions::none()).unwrap(),
+            json!({ "parse": [], "replied_user": false })
+        );
+    }
+
+    #[test]
+    fn test_validate_content_counts_characters() {
+        assert!(validate_content(&"é".repeat(2000)).is_ok());
+        assert!(matches!(
+            validate_content(&"é".repeat(2001)),
+            Err(ClientError::MessageTooLong)
+        ));
+    }
+
+    #[test]
+    fn test_validate_embeds_accepts_limits() {
+        let embed = Embed {
+            title: Some("t".repeat(256)),
+            fields: vec![field("name", "value"); 25],
+            ..Default::default()
+        };
+
+        assert!(validate_embeds(&[embed]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_embeds_title_too_long() {
+        let embed = Embed {
+            title: Some("t".repeat(257)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            validate_embeds(&[embed]),
+            Err(ClientError::InvalidEmbed(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_embeds_too_many_fields() {
+        let embed = Embed {
+            fields: vec![field("name", "value"); 26],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            validate_embeds(&[embed]),
+            Err(ClientError::InvalidEmbed(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_embeds_field_value_too_long() {
+        let embed = Embed {
+            fields: vec![field("name", &"v".repeat(1025))],
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            validate_embeds(&[embed]),
+            Err(ClientError::InvalidEmbed(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_embeds_total_too_long() {
+        let embed = Embed {
+            description: Some("d".repeat(4000)),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            validate_embeds(&[embed.clone(), embed]),
+            Err(ClientError::InvalidEmbed(_))
+        ));
+    }
+
+    #[test]
+    fn test_create_command_serializes_nested_options() {
+        let command = CreateCommand::chat_input("deploy", "Deploy a service").option(
+            CommandOption::new(CommandOptionType::SubCommand, "start", "Start a deploy").option(
+                CommandOption::new(CommandOptionType::String, "env", "Target environment")
+                    .required(),
+            ),
+        );
+
+        assert!(command.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&command).unwrap(),
+            json!({
+                "name": "deploy",
+                "description": "Deploy a service",
+                "type": 1,
+                "options": [{
+                    "type": 1,
+                    "name": "start",
+                    "description": "Start a deploy",
+                    "options": [{
+                        "type": 3,
+                        "name": "env",
+                        "description": "Target environment",
+                        "required": true
+                    }]
+                }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_command_rejects_uppercase_name() {
+        let command = CreateCommand::chat_input("Deploy", "Deploy a service");
+
+        assert!(matches!(
+            command.validate(),
+            Err(ClientError::InvalidCommand(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_command_rejects_required_after_optional() {
+        let command = CreateCommand::chat_input("ban", "Ban a member")
+            .option(CommandOption::new(
+                CommandOptionType::String,
+                "reason",
+                "Why",
+            ))
+            .option(CommandOption::new(CommandOptionType::User, "user", "Who").required());
+
+        assert!(matches!(
+            command.validate(),
+            Err(ClientError::InvalidCommand(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_context_menu_command_without_description() {
+        let command = CreateCommand {
+            kind: CommandType::User,
+            ..CreateCommand::chat_input("Show Profile", "")
+        };
+
+        assert!(command.validate().is_ok());
+    }
+}
@@ -1,56 +1,55 @@
 use rand::RngExt;
 use reqwest::{
-    Client, Method, Response, StatusCode,
+    Certificate, Client, Proxy, Response, StatusCode,
     header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT},
 };
 use serde::de::DeserializeOwned;
 use std::time::Duration;
 use tokio::time::sleep;
 
-use crate::errors::client::ClientError;
+use crate::{
+    auth::Token,
+    errors::{client::ClientError, discord::DiscordError},
+    ratelimit::{RateLimitInfo, RateLimiter},
+    request::Request,
+    routes::Route,
+};
 
 #[derive(Debug, Clone)]
 pub struct HttpClient {
     http: Client,
-    token: String,
+    token: Option<Token>,
     base_url: String,
+    rate_limiter: RateLimiter,
+    max_rate_limit_retries: u32,
 }
 
 impl HttpClient {
     const MAX_RETRIES: u32 = 3;
     const INITIAL_BACKOFF_MS: u64 = 1000;
+    const DEFAULT_RATE_LIMIT_RETRIES: u32 = 3;
 
     const BASE_URL: &str = "https://discord.com/api/v10";
     const USER_AGENT_VALUE: &str = "DiscordBot (https://github.com/ovasconcelos/discline, 0.1.0)";
 
-    pub fn new(token: String) -> Self {
-        let mut headers = HeaderMap::new();
-        let auth_token = format!("Bot {}", token);
+    const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+    const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
 
-        headers.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&auth_token).expect("Invalid token format"),
-        );
-        headers.insert(USER_AGENT, HeaderValue::from_static(Self::USER_AGENT_VALUE));
-
-        let http = Client::builder()
-            .default_headers(headers)
-            .build()
-            .expect("Failed to build HTTP client");
+    pub fn new(token: Token) -> Result<Self, ClientError> {
+        Self::builder(token).build()
+    }
 
-        Self {
-            http,
-            token,
-            base_url: Self::BASE_URL.to_string(),
-        }
+    pub fn builder(token: Token) -> HttpClientBuilder {
+        HttpClientBuilder::new(token)
     }
 
     pub fn http(&self) -> &Client {
         &self.http
     }
 
-    pub fn token(&self) -> &str {
-        &self.token
+    /// The token requests are authenticated with, if any.
+    pub fn token(&self) -> Option<&Token> {
+        self.token.as_ref()
     }
 
     pub fn base_url(&self) -> &str {
@@ -61,6 +60,20 @@ impl HttpClient {
         self.base_url = url;
     }
 
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    pub fn max_rate_limit_retries(&self) -> u32 {
+        self.max_rate_limit_retries
+    }
+
+    /// Sets how many 429 responses a single request waits out and retries
+    /// before giving up with `ClientError::RateLimited`.
+    pub fn set_max_rate_limit_retries(&mut self, retries: u32) {
+        self.max_rate_limit_retries = retries;
+    }
+
     pub async fn wait(&self, attempt: u32) {
         let backoff = Self::INITIAL_BACKOFF_MS * 2u64.pow(attempt);
         let jitter = rand::rng().random_range(0..100);
@@ -70,89 +83,276 @@ impl HttpClient {
 
     pub async fn handle_error_status<T: DeserializeOwned>(
         &self,
+        route: Route<'_>,
         status: StatusCode,
         response: Response,
     ) -> Result<T, ClientError> {
         match status {
             StatusCode::UNAUTHORIZED => Err(ClientError::Unauthorized),
-            StatusCode::FORBIDDEN => Err(ClientError::Forbidden),
-            StatusCode::NOT_FOUND => Err(ClientError::NotFound {
-                resource_type: "Resource".to_string(),
-                resource_id: "Unknown".to_string(),
-            }),
             StatusCode::TOO_MANY_REQUESTS => {
-                let retry_after = response
-                    .headers()
-                    .get("retry-after")
-                    .and_then(|h| h.to_str().ok())
-                    .and_then(|s| s.parse::<u64>().ok())
-                    .unwrap_or(1);
-
-                Err(ClientError::RateLimited { retry_after })
+                let info = RateLimitInfo::from_response(response).await;
+
+                Err(ClientError::RateLimited {
+                    retry_after: info.retry_after.as_secs_f64(),
+                    global: info.global,
+                })
             }
             _ => {
-                let error_message = response.text().await.unwrap_or_default();
-                Err(ClientError::ApiError {
-                    status: status.as_u16(),
-                    message: error_message,
-                })
+                let body = response.text().await.unwrap_or_default();
+                let error = DiscordError::parse(status.as_u16(), &body);
+
+                match (status, error) {
+                    (StatusCode::FORBIDDEN, error) => Err(ClientError::Forbidden(error)),
+                    (StatusCode::NOT_FOUND, error) => {
+                        let (resource_type, resource_id) = route.resource();
+
+                        Err(ClientError::NotFound {
+                            resource_type: resource_type.to_string(),
+                            resource_id,
+                            error,
+                        })
+                    }
+                    (_, Some(error)) => Err(ClientError::Discord(error)),
+                    (_, None) => Err(ClientError::ApiError {
+                        status: status.as_u16(),
+                        message: body,
+                    }),
+                }
             }
         }
     }
 
-    pub async fn request<
-        T: DeserializeOwned,
-        B: serde::Serialize + Clone + std::marker::Copy,
-        Q: serde::Serialize,
-    >(
+    /// Sends `request`, waiting out rate limits and retrying network and
+    /// server errors. The request is rebuilt from the same body each attempt.
+    ///
+    /// Network errors on webhook and interaction routes leave out the URL,
+    /// which contains the token.
+    pub async fn send<T: DeserializeOwned>(
         &self,
-        method: Method,
-        endpoint: &str,
-        body: Option<B>,
-        query: Option<Q>,
+        mut request: Request<'_>,
     ) -> Result<T, ClientError> {
-        let url = format!("{}/{}", self.base_url(), endpoint.trim_start_matches('/'));
+        if let Some(error) = request.take_error() {
+            return Err(error);
+        }
 
-        for attempt in 0..Self::MAX_RETRIES {
-            let mut request = self.http().request(method.clone(), &url);
+        let result = self.execute(&request).await;
 
-            if let Some(b) = body {
-                request = request.json(&b);
-            }
+        if request.route().has_token() {
+            result.map_err(ClientError::without_url)
+        } else {
+            result
+        }
+    }
 
-            if let Some(q) = &query {
-                request = request.query(&q);
-            }
+    async fn execute<T: DeserializeOwned>(&self, request: &Request<'_>) -> Result<T, ClientError> {
+        let route = request.route();
+        let method = route.method();
+        let url = format!("{}{}", self.base_url(), route.path());
+        let key = route.key();
+
+        let mut attempt = 0;
+        let mut rate_limit_retries = 0;
 
-            let response = match request.send().await {
+        loop {
+            self.rate_limiter.acquire(&key).await;
+
+            let builder = request.apply(self.http().request(method.clone(), &url))?;
+
+            let response = match builder.send().await {
                 Ok(res) => res,
                 Err(_e) if attempt < Self::MAX_RETRIES - 1 => {
                     self.wait(attempt).await;
+                    attempt += 1;
                     continue;
                 }
                 Err(e) => return Err(ClientError::Network(e)),
             };
 
             let status = response.status();
+            self.rate_limiter.update(&key, response.headers()).await;
 
             if status.is_success() {
-                return response
-                    .json::<T>()
-                    .await
+                let bytes = response.bytes().await?;
+                let bytes: &[u8] = if bytes.is_empty() { b"null" } else { &bytes };
+
+                return serde_json::from_slice::<T>(bytes)
                     .map_err(|e| ClientError::ParseError(e.to_string()));
             }
 
+            if status == StatusCode::TOO_MANY_REQUESTS {
+                let info = RateLimitInfo::from_response(response).await;
+                self.rate_limiter.block(&key, info).await;
+
+                if rate_limit_retries < self.max_rate_limit_retries {
+                    rate_limit_retries += 1;
+                    continue;
+                }
+
+                return Err(ClientError::RateLimited {
+                    retry_after: info.retry_after.as_secs_f64(),
+                    global: info.global,
+                });
+            }
+
             if status.is_server_error() && attempt < Self::MAX_RETRIES - 1 {
                 self.wait(attempt).await;
+                attempt += 1;
                 continue;
             }
 
-            return self.handle_error_status(status, response).await;
+            return self.handle_error_status(route, status, response).await;
+        }
+    }
+}
+
+/// Configures the connection settings of an `HttpClient`.
+///
+/// Connections time out after 10 seconds and reads after 30 seconds of
+/// silence unless overridden; there is no overall request timeout by default
+/// so large uploads aren't cut off.
+#[derive(Debug, Clone)]
+pub struct HttpClientBuilder {
+    token: Option<Token>,
+    base_url: String,
+    user_agent: String,
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    max_rate_limit_retries: u32,
+}
+
+impl HttpClientBuilder {
+    pub fn new(token: Token) -> Self {
+        Self {
+            token: Some(token),
+            ..Self::unauthenticated()
         }
+    }
 
-        Err(ClientError::ApiError {
-            status: 500,
-            message: "Max retries reached".into(),
+    /// A client that sends no `Authorization` header, for endpoints such as
+    /// webhooks that authenticate through the URL.
+    pub fn unauthenticated() -> Self {
+        Self {
+            token: None,
+            base_url: HttpClient::BASE_URL.to_string(),
+            user_agent: HttpClient::USER_AGENT_VALUE.to_string(),
+            connect_timeout: Some(HttpClient::DEFAULT_CONNECT_TIMEOUT),
+            read_timeout: Some(HttpClient::DEFAULT_READ_TIMEOUT),
+            timeout: None,
+            proxy: None,
+            root_certificates: Vec::new(),
+            max_rate_limit_retries: HttpClient::DEFAULT_RATE_LIMIT_RETRIES,
+        }
+    }
+
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into();
+        self
+    }
+
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// Limits how long establishing a connection may take. `None` waits
+    /// indefinitely.
+    pub fn connect_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Limits how long a response may go without sending data. `None` waits
+    /// indefinitely.
+    pub fn read_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Limits the total time of each attempt, from connecting until the body
+    /// has been read.
+    pub fn timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Routes every request through a proxy, given as an `http://`,
+    /// `https://`, `socks5://` or `socks5h://` URL.
+    pub fn proxy(mut self, url: impl Into<String>) -> Self {
+        self.proxy = Some(url.into());
+        self
+    }
+
+    /// Trusts the PEM-encoded certificates in `pem` in addition to the
+    /// system roots.
+    pub fn root_certificate_pem(mut self, pem: impl Into<Vec<u8>>) -> Self {
+        self.root_certificates.push(pem.into());
+        self
+    }
+
+    pub fn max_rate_limit_retries(mut self, retries: u32) -> Self {
+        self.max_rate_limit_retries = retries;
+        self
+    }
+
+    pub fn build(self) -> Result<HttpClient, ClientError> {
+        let mut headers = HeaderMap::new();
+
+        if let Some(token) = &self.token {
+            headers.insert(AUTHORIZATION, token.header_value()?);
+        }
+
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&self.user_agent)
+                .map_err(|_| ClientError::InvalidConfig("Invalid user agent".to_string()))?,
+        );
+
+        let mut builder = Client::builder().default_headers(headers);
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        if let Some(url) = &self.proxy {
+            let proxy = Proxy::all(url)
+                .map_err(|e| ClientError::InvalidConfig(format!("Invalid proxy URL: {}", e)))?;
+            builder = builder.proxy(proxy);
+        }
+
+        for pem in &self.root_certificates {
+            let certificates = Certificate::from_pem_bundle(pem)
+                .map_err(|e| ClientError::InvalidConfig(format!("Invalid certificate: {}", e)))?;
+
+            if certificates.is_empty() {
+                return Err(ClientError::InvalidConfig(
+                    "No certificates found in PEM data".to_string(),
+                ));
+            }
+
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+
+        Ok(HttpClient {
+            http: builder
+                .build()
+                .map_err(|e| ClientError::InvalidConfig(e.to_string()))?,
+            token: self.token,
+            base_url: self.base_url,
+            rate_limiter: RateLimiter::new(),
+            max_rate_limit_retries: self.max_rate_limit_retries,
         })
     }
 }
@@ -163,10 +363,52 @@ mod tests {
 
     #[test]
     fn test_http_client_new() {
-        let token = "test-token".to_string();
-        let client = HttpClient::new(token.clone());
+        let token = Token::bot("test-token");
+        let client = HttpClient::new(token.clone()).unwrap();
 
-        assert_eq!(client.token(), &token);
+        assert_eq!(client.token(), Some(&token));
         assert_eq!(client.base_url(), HttpClient::BASE_URL);
     }
+
+    #[test]
+    fn test_http_client_new_invalid_token() {
+        let result = HttpClient::new(Token::bot("bad\ntoken"));
+
+        assert!(matches!(result, Err(ClientError::InvalidToken)));
+    }
+
+    #[test]
+    fn test_builder_overrides() {
+        let client = HttpClient::builder(Token::bot("test-token"))
+            .base_url("http://localhost:1234")
+            .user_agent("discline-test")
+            .timeout(Some(Duration::from_secs(5)))
+            .proxy("socks5h://127.0.0.1:1080")
+            .max_rate_limit_retries(0)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url(), "http://localhost:1234");
+        assert_eq!(client.max_rate_limit_retries(), 0);
+    }
+
+    #[test]
+    fn test_builder_invalid_proxy() {
+        let result = HttpClient::builder(Token::bot("test-token"))
+            .proxy("not a url")
+            .build();
+
+        assert!(matches!(result, Err(ClientError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_builder_invalid_certificate() {
+        let result = HttpClient::builder(Token::bot("test-token"))
+            .root_certificate_pem(
+                b"-----BEGIN CERTIFICATE-----\ngarbage\n-----END CERTIFICATE-----\n".to_vec(),
+            )
+            .build();
+
+        assert!(matches!(result, Err(ClientError::InvalidConfig(_))));
+    }
 }
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::errors::discord::{DiscordError, ErrorCode};
+
 #[derive(Error, Debug)]
 pub enum ClientError {
     #[error("Network error: {0}")]
@@ -8,24 +10,82 @@ pub enum ClientError {
     #[error("Unauthorized: Invalid Discord token")]
     Unauthorized,
 
+    #[error("Invalid token: Contains characters that are not allowed in an HTTP header")]
+    InvalidToken,
+
+    #[error("Invalid client configuration: {0}")]
+    InvalidConfig(String),
+
+    #[error("Invalid webhook URL: {0}")]
+    InvalidWebhookUrl(String),
+
+    /// A 403. Holds Discord's error envelope when the response had one.
     #[error("Forbidden: Missing permissions to access this resource")]
-    Forbidden,
+    Forbidden(Option<DiscordError>),
 
+    /// A 404 for the resource the route addresses. Holds Discord's error
+    /// envelope when the response had one.
     #[error("Not found: {resource_type} with ID {resource_id} doesn't exist")]
     NotFound {
         resource_type: String,
         resource_id: String,
+        error: Option<DiscordError>,
     },
 
     #[error("Rate limited: Retry after {retry_after} seconds")]
-    RateLimited { retry_after: u64 },
+    RateLimited { retry_after: f64, global: bool },
 
     #[error("Discord API error: {status} - {message}")]
     ApiError { status: u16, message: String },
 
+    /// Any other failed request that came with Discord's error envelope.
+    #[error("Discord API error: {0}")]
+    Discord(DiscordError),
+
     #[error("Failed to parse response: {0}")]
     ParseError(String),
 
     #[error("Message too long: Maximum length is 2000 characters")]
     MessageTooLong,
+
+    #[error("Invalid embed: {0}")]
+    InvalidEmbed(String),
+
+    #[error("Too many attachments: Maximum is 10 files per message, got {count}")]
+    TooManyAttachments { count: usize },
+
+    #[error("Failed to read file: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Bulk delete requires between 2 and 100 messages, got {count}")]
+    InvalidBulkDeleteCount { count: usize },
+
+    #[error("Invalid application command: {0}")]
+    InvalidCommand(String),
+
+    #[error("Invalid interaction response: {0}")]
+    InvalidInteractionResponse(String),
+}
+
+impl ClientError {
+    /// The Discord JSON error code, when the API returned one.
+    pub fn code(&self) -> Option<ErrorCode> {
+        match self {
+            ClientError::Discord(error)
+            | ClientError::Forbidden(Some(error))
+            | ClientError::NotFound {
+                error: Some(error), ..
+            } => Some(error.code),
+            _ => None,
+        }
+    }
+
+    /// Strips the request URL from network errors, for routes whose path
+    /// carries a token.
+    pub(crate) fn without_url(self) -> Self {
+        match self {
+            ClientError::Network(error) => ClientError::Network(error.without_url()),
+            other => other,
+        }
+    }
 }
@@ -0,0 +1,251 @@
+use serde::Deserialize;
+use serde_json::Value;
+use std::fmt;
+
+/// Discord's JSON error codes. Only the codes callers commonly branch on
+/// are named; anything else is kept as `Other`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCode {
+    General,
+    UnknownChannel,
+    UnknownGuild,
+    UnknownMember,
+    UnknownMessage,
+    UnknownRole,
+    UnknownUser,
+    UnknownEmoji,
+    UnknownWebhook,
+    UnknownInteraction,
+    UnknownApplicationCommand,
+    MaximumPinsReached,
+    MissingAccess,
+    CannotSendEmptyMessage,
+    CannotSendToUser,
+    MissingPermissions,
+    InvalidToken,
+    InvalidFormBody,
+    Other(u32),
+}
+
+impl From<u32> for ErrorCode {
+    fn from(code: u32) -> Self {
+        match code {
+            0 => ErrorCode::General,
+            10003 => ErrorCode::UnknownChannel,
+            10004 => ErrorCode::UnknownGuild,
+            10007 => ErrorCode::UnknownMember,
+            10008 => ErrorCode::UnknownMessage,
+            10011 => ErrorCode::UnknownRole,
+            10013 => ErrorCode::UnknownUser,
+            10014 => ErrorCode::UnknownEmoji,
+            10015 => ErrorCode::UnknownWebhook,
+            10062 => ErrorCode::UnknownInteraction,
+            10063 => ErrorCode::UnknownApplicationCommand,
+            30003 => ErrorCode::MaximumPinsReached,
+            50001 => ErrorCode::MissingAccess,
+            50006 => ErrorCode::CannotSendEmptyMessage,
+            50007 => ErrorCode::CannotSendToUser,
+            50013 => ErrorCode::MissingPermissions,
+            50014 => ErrorCode::InvalidToken,
+            50035 => ErrorCode::InvalidFormBody,
+            other => ErrorCode::Other(other),
+        }
+    }
+}
+
+impl From<ErrorCode> for u32 {
+    fn from(code: ErrorCode) -> Self {
+        match code {
+            ErrorCode::General => 0,
+            ErrorCode::UnknownChannel => 10003,
+            ErrorCode::UnknownGuild => 10004,
+            ErrorCode::UnknownMember => 10007,
+            ErrorCode::UnknownMessage => 10008,
+            ErrorCode::UnknownRole => 10011,
+            ErrorCode::UnknownUser => 10013,
+            ErrorCode::UnknownEmoji => 10014,
+            ErrorCode::UnknownWebhook => 10015,
+            ErrorCode::UnknownInteraction => 10062,
+            ErrorCode::UnknownApplicationCommand => 10063,
+            ErrorCode::MaximumPinsReached => 30003,
+            ErrorCode::MissingAccess => 50001,
+            ErrorCode::CannotSendEmptyMessage => 50006,
+            ErrorCode::CannotSendToUser => 50007,
+            ErrorCode::MissingPermissions => 50013,
+            ErrorCode::InvalidToken => 50014,
+            ErrorCode::InvalidFormBody => 50035,
+            ErrorCode::Other(other) => other,
+        }
+    }
+}
+
+/// A field-level validation error, with `path` pointing at the offending
+/// field, e.g. `embeds.0.title`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub path: String,
+    pub code: String,
+    pub message: String,
+}
+
+/// The error envelope Discord returns on failed requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscordError {
+    pub status: u16,
+    pub code: ErrorCode,
+    pub message: String,
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Deserialize)]
+struct ErrorEnvelope {
+    code: u32,
+    message: String,
+    #[serde(default)]
+    errors: Value,
+}
+
+impl DiscordError {
+    /// Parses a response body, returning `None` when it isn't Discord's
+    /// error envelope.
+    pub fn parse(status: u16, body: &str) -> Option<Self> {
+        let envelope: ErrorEnvelope = serde_json::from_str(body).ok()?;
+
+        let mut errors = Vec::new();
+        flatten_errors(&envelope.errors, &mut Vec::new(), &mut errors);
+
+        Some(Self {
+            status,
+            code: envelope.code.into(),
+            message: envelope.message,
+            errors,
+        })
+    }
+}
+
+impl fmt::Display for DiscordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (code {}, status {})",
+            self.message,
+            u32::from(self.code),
+            self.status
+        )?;
+
+        for error in &self.errors {
+            write!(f, "; {}: {}", error.path, error.message)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn flatten_errors(value: &Value, path: &mut Vec<String>, out: &mut Vec<FieldError>) {
+    let Value::Object(map) = value else {
+        return;
+    };
+
+    for (key, child) in map {
+        if key == "_errors" {
+            for error in child.as_array().into_iter().flatten() {
+                out.push(FieldError {
+                    path: path.join("."),
+                    code: error["code"].as_str().unwrap_or_default().to_string(),
+                    message: error["message"].as_str().unwrap_or_default().to_string(),
+                });
+            }
+        } else {
+            path.push(key.clone());
+            flatten_errors(child, path, out);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_simple_error() {
+        let error =
+            DiscordError::parse(404, r#"{"message": "Unknown Channel", "code": 10003}"#).unwrap();
+
+        assert_eq!(error.code, ErrorCode::UnknownChannel);
+        assert_eq!(error.message, "Unknown Channel");
+        assert!(error.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_flattens_field_errors() {
+        let body = r#"{
+            "code": 50035,
+            "message": "Invalid Form Body",
+            "errors": {
+                "embeds": {
+                    "0": {
+                        "title": {
+                            "_errors": [{
+                                "code": "BASE_TYPE_MAX_LENGTH",
+                                "message": "Must be 256 or fewer in length."
+                            }]
+                        }
+                    }
+                },
+                "content": {
+                    "_errors": [{ "code": "BASE_TYPE_REQUIRED", "message": "This field is required" }]
+                }
+            }
+        }"#;
+
+        let error = DiscordError::parse(400, body).unwrap();
+
+        assert_eq!(error.code, ErrorCode::InvalidFormBody);
+        assert_eq!(
+            error.errors,
+            vec![
+                FieldError {
+                    path: "content".to_string(),
+                    code: "BASE_TYPE_REQUIRED".to_string(),
+                    message: "This field is required".to_string(),
+                },
+                FieldError {
+                    path: "embeds.0.title".to_string(),
+                    code: "BASE_TYPE_MAX_LENGTH".to_string(),
+                    message: "Must be 256 or fewer in length.".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_non_envelope() {
+        assert!(DiscordError::parse(502, "<html>Bad Gateway</html>").is_none());
+    }
+
+    #[test]
+    fn test_unknown_code_round_trips() {
+        assert_eq!(ErrorCode::from(40060), ErrorCode::Other(40060));
+        assert_eq!(u32::from(ErrorCode::Other(40060)), 40060);
+    }
+
+    #[test]
+    fn test_display_includes_field_paths() {
+        let error = DiscordError {
+            status: 400,
+            code: ErrorCode::InvalidFormBody,
+            message: "Invalid Form Body".to_string(),
+            errors: vec![FieldError {
+                path: "embeds.0.title".to_string(),
+                code: "BASE_TYPE_MAX_LENGTH".to_string(),
+                message: "Must be 256 or fewer in length.".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid Form Body (code 50035, status 400); embeds.0.title: Must be 256 or fewer in length."
+        );
+    }
+}
@@ -12,6 +12,33 @@ pub enum GatewayError {
     #[error("Authentication failed")]
     AuthenticationFailed,
 
+    #[error(
+        "Disallowed intents: enable the privileged intents (e.g. MESSAGE_CONTENT) for the \
+         application in the Developer Portal, or stop requesting them"
+    )]
+    DisallowedIntents,
+
+    #[error("Invalid intents: the intents sent in IDENTIFY are not valid")]
+    InvalidIntents,
+
+    #[error("Invalid shard")]
+    InvalidShard,
+
+    #[error("Sharding required: the bot is in too many guilds for a single connection")]
+    ShardingRequired,
+
+    #[error("Invalid gateway API version")]
+    InvalidApiVersion,
+
+    #[error("Invalid sequence sent when resuming")]
+    InvalidSequence,
+
+    #[error("Session timed out")]
+    SessionTimedOut,
+
+    #[error("Unsupported token: The gateway only accepts bot tokens")]
+    UnsupportedToken,
+
     #[error("Unexpected gateway closure")]
     UnexpectedClose,
 
@@ -20,4 +47,23 @@ pub enum GatewayError {
 
     #[error("Reconnect required")]
     ReconnectRequired,
+
+    #[error("Heartbeat not acknowledged, the connection is dead")]
+    HeartbeatTimeout,
+}
+
+impl GatewayError {
+    /// Whether reconnecting cannot help, e.g. because the token is invalid.
+    pub fn is_fatal(&self) -> bool {
+        matches!(
+            self,
+            GatewayError::AuthenticationFailed
+                | GatewayError::DisallowedIntents
+                | GatewayError::InvalidIntents
+                | GatewayError::InvalidShard
+                | GatewayError::ShardingRequired
+                | GatewayError::InvalidApiVersion
+                | GatewayError::UnsupportedToken
+        )
+    }
 }
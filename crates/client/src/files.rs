@@ -0,0 +1,74 @@
+use reqwest::multipart::Part;
+use std::path::Path;
+
+use crate::errors::client::ClientError;
+
+/// A file to upload alongside a message.
+#[derive(Debug, Clone)]
+pub struct FileUpload {
+    pub filename: String,
+    pub content_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+impl FileUpload {
+    pub fn from_bytes(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            content_type: None,
+            data: data.into(),
+        }
+    }
+
+    /// Reads the file at `path`, using its file name as the upload name.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, ClientError> {
+        let path = path.as_ref();
+        let data = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "file".to_string());
+
+        Ok(Self::from_bytes(filename, data))
+    }
+
+    pub fn with_content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    pub(crate) fn to_part(&self) -> Result<Part, ClientError> {
+        let part = Part::bytes(self.data.clone()).file_name(self.filename.clone());
+
+        match &self.content_type {
+            Some(content_type) => Ok(part.mime_str(content_type)?),
+            None => Ok(part),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_file_upload_from_path() {
+        let path = std::env::temp_dir().join("discline-upload-test.log");
+        tokio::fs::write(&path, b"build ok").await.unwrap();
+
+        let file = FileUpload::from_path(&path).await.unwrap();
+
+        assert_eq!(file.filename, "discline-upload-test.log");
+        assert_eq!(file.data, b"build ok");
+        assert_eq!(file.content_type, None);
+
+        tokio::fs::remove_file(&path).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_file_upload_from_missing_path() {
+        let result = FileUpload::from_path("/nonexistent/discline.log").await;
+
+        assert!(matches!(result, Err(ClientError::Io(_))));
+    }
+}
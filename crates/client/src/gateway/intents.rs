@@ -0,0 +1,81 @@
+use bitflags::bitflags;
+use serde::{Deserialize, Serialize};
+
+bitflags! {
+    /// The groups of gateway events a connection subscribes to, sent in
+    /// IDENTIFY.
+    ///
+    /// `GUILD_MEMBERS`, `GUILD_PRESENCES` and `MESSAGE_CONTENT` are
+    /// privileged: they must be enabled for the application in the Developer
+    /// Portal, or Discord closes the connection with
+    /// `GatewayError::DisallowedIntents`.
+    ///
+    /// In human-readable formats such as TOML, intents are written as names
+    /// joined by `|`, e.g. `"GUILDS | GUILD_MESSAGES | MESSAGE_CONTENT"`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    #[serde(transparent)]
+    pub struct Intents: u32 {
+        const GUILDS = 1 << 0;
+        const GUILD_MEMBERS = 1 << 1;
+        const GUILD_MODERATION = 1 << 2;
+        const GUILD_EXPRESSIONS = 1 << 3;
+        const GUILD_INTEGRATIONS = 1 << 4;
+        const GUILD_WEBHOOKS = 1 << 5;
+        const GUILD_INVITES = 1 << 6;
+        const GUILD_VOICE_STATES = 1 << 7;
+        const GUILD_PRESENCES = 1 << 8;
+        const GUILD_MESSAGES = 1 << 9;
+        const GUILD_MESSAGE_REACTIONS = 1 << 10;
+        const GUILD_MESSAGE_TYPING = 1 << 11;
+        const DIRECT_MESSAGES = 1 << 12;
+        const DIRECT_MESSAGE_REACTIONS = 1 << 13;
+        const DIRECT_MESSAGE_TYPING = 1 << 14;
+        const MESSAGE_CONTENT = 1 << 15;
+        const GUILD_SCHEDULED_EVENTS = 1 << 16;
+        const AUTO_MODERATION_CONFIGURATION = 1 << 20;
+        const AUTO_MODERATION_EXECUTION = 1 << 21;
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
+    }
+}
+
+impl Intents {
+    pub const PRIVILEGED: Intents = Intents::GUILD_MEMBERS
+        .union(Intents::GUILD_PRESENCES)
+        .union(Intents::MESSAGE_CONTENT);
+
+    /// Every intent that needs no approval in the Developer Portal.
+    pub const fn non_privileged() -> Self {
+        Intents::all().difference(Intents::PRIVILEGED)
+    }
+}
+
+impl Default for Intents {
+    /// The non-privileged intents, which every application may request.
+    /// Privileged intents have to be added explicitly; without
+    /// `MESSAGE_CONTENT`, messages that don't mention the bot arrive with an
+    /// empty `content`.
+    fn default() -> Self {
+        Intents::non_privileged()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_intents_are_not_privileged() {
+        let intents = Intents::default();
+
+        assert!(intents.contains(Intents::GUILDS | Intents::GUILD_MESSAGES));
+        assert!(!intents.intersects(Intents::PRIVILEGED));
+    }
+
+    #[test]
+    fn test_intents_bits() {
+        let intents = Intents::GUILDS | Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+
+        assert_eq!(intents.bits(), 33281);
+    }
+}
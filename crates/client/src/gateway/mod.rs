@@ -0,0 +1,680 @@
+use futures_util::stream::{SplitSink, SplitStream};
+use futures_util::{SinkExt, StreamExt};
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::TcpStream;
+use tokio::sync::{Mutex, mpsc};
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message as WsMessage;
+use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream, connect_async};
+
+use crate::auth::{Token, TokenKind};
+use crate::errors::gateway::GatewayError;
+use types::interaction::Interaction;
+use types::message::Message;
+use types::user::User;
+
+pub mod intents;
+pub mod supervisor;
+
+pub use intents::Intents;
+pub use supervisor::{ConnectionState, GatewaySupervisor};
+
+type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+type WsSink = SplitSink<WsStream, WsMessage>;
+
+/// Closing with this code keeps the session resumable.
+const CLOSE_RESUMABLE: u16 = 4000;
+const CLOSE_AUTHENTICATION_FAILED: u16 = 4004;
+const CLOSE_INVALID_SEQUENCE: u16 = 4007;
+const CLOSE_SESSION_TIMED_OUT: u16 = 4009;
+const CLOSE_INVALID_SHARD: u16 = 4010;
+const CLOSE_SHARDING_REQUIRED: u16 = 4011;
+const CLOSE_INVALID_API_VERSION: u16 = 4012;
+const CLOSE_INVALID_INTENTS: u16 = 4013;
+const CLOSE_DISALLOWED_INTENTS: u16 = 4014;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GatewayPayload {
+    pub op: u8,
+    #[serde(default)]
+    pub d: Value,
+    pub s: Option<u64>,
+    pub t: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HelloData {
+    pub heartbeat_interval: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentifyPayload {
+    pub op: u8,
+    pub d: IdentifyData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentifyData {
+    pub token: String,
+    pub properties: IdentifyProperties,
+    pub intents: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct IdentifyProperties {
+    pub os: String,
+    pub browser: String,
+    pub device: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResumePayload {
+    pub op: u8,
+    pub d: ResumeData,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResumeData {
+    pub token: String,
+    pub session_id: String,
+    pub seq: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReadyData {
+    pub user: User,
+    pub guilds: Vec<Value>,
+    pub session_id: String,
+    #[serde(default)]
+    pub resume_gateway_url: Option<String>,
+}
+
+/// The state of the last heartbeat, shared with the heartbeat task.
+#[derive(Debug)]
+struct Heartbeat {
+    sent_at: Option<Instant>,
+    acked: bool,
+    latency: Option<Duration>,
+}
+
+impl Heartbeat {
+    fn new() -> Self {
+        Self {
+            sent_at: None,
+            acked: true,
+            latency: None,
+        }
+    }
+
+    fn sent(&mut self) {
+        self.sent_at = Some(Instant::now());
+        self.acked = false;
+    }
+
+    /// Records a heartbeat sent because Discord asked for one. A beat that
+    /// is still unacknowledged stays outstanding, so an op 1 request can't
+    /// hide a zombied connection from the heartbeat task.
+    fn requested(&mut self) {
+        if self.acked {
+            self.sent();
+        }
+    }
+
+    fn acked(&mut self) {
+        self.acked = true;
+        self.latency = self.sent_at.map(|sent_at| sent_at.elapsed());
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Event {
+    Ready {
+        user: User,
+        guilds: Vec<Value>,
+        session_id: String,
+    },
+    MessageCreate(Message),
+    /// A command, component or modal submission to respond to with
+    /// `RestClient::create_interaction_response`.
+    InteractionCreate(Box<Interaction>),
+    /// A `Gateway::reconnect` resumed the session. Events missed while
+    /// disconnected have been replayed before this one.
+    Resumed,
+    /// Emitted by `GatewaySupervisor` as it connects and reconnects; a plain
+    /// `Gateway` never produces it.
+    ConnectionState(ConnectionState),
+}
+
+/// How to connect to the gateway. The default is Discord's gateway with
+/// `Intents::default()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GatewayOptions {
+    pub url: String,
+    pub intents: Intents,
+}
+
+impl GatewayOptions {
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = url.to_string();
+        self
+    }
+
+    pub fn with_intents(mut self, intents: Intents) -> Self {
+        self.intents = intents;
+        self
+    }
+}
+
+impl Default for GatewayOptions {
+    fn default() -> Self {
+        Self {
+            url: Gateway::GATEWAY_URL.to_string(),
+            intents: Intents::default(),
+        }
+    }
+}
+
+pub struct Gateway {
+    pub ws_stream: SplitStream<WsStream>,
+    pub ws_sink: Arc<Mutex<WsSink>>,
+    pub heartbeat_interval: Duration,
+    pub last_sequence: Arc<Mutex<Option<u64>>>,
+    pub token: Token,
+    pub session_id: Option<String>,
+    /// Where to reconnect to resume the session, as given by READY.
+    pub resume_gateway_url: Option<String>,
+    url: String,
+    intents: Intents,
+    heartbeat: Arc<std::sync::Mutex<Heartbeat>>,
+    /// Aborted on `reconnect`, `close` and drop, so the task never outlives
+    /// the connection it beats for.
+    heartbeat_task: JoinHandle<()>,
+    /// Receives the error that stopped the heartbeat task, surfaced by
+    /// `next_event`.
+    heartbeat_failures: mpsc::Receiver<GatewayError>,
+    /// Set by an invalid session: when to resume or identify again, and
+    /// whether the session can be resumed.
+    session_retry: Option<(tokio::time::Instant, bool)>,
+}
+
+impl Gateway {
+    pub const GATEWAY_URL: &str = "wss://gateway.discord.gg/?v=10&encoding=json";
+
+    pub async fn connect(token: Token) -> Result<Self, GatewayError> {
+        Self::connect_with_url(token, Self::GATEWAY_URL).await
+    }
+
+    pub async fn connect_with_url(token: Token, url: &str) -> Result<Self, GatewayError> {
+        Self::connect_with_options(token, GatewayOptions::default().with_url(url)).await
+    }
+
+    pub async fn connect_with_options(
+        token: Token,
+        options: GatewayOptions,
+    ) -> Result<Self, GatewayError> {
+        if token.kind() != TokenKind::Bot {
+            return Err(GatewayError::UnsupportedToken);
+        }
+
+        let (sink, stream, heartbeat_interval) = open(&options.url).await?;
+
+        let last_sequence = Arc::new(Mutex::new(None));
+        let ws_sink = Arc::new(Mutex::new(sink));
+        let heartbeat = Arc::new(std::sync::Mutex::new(Heartbeat::new()));
+        let (heartbeat_task, heartbeat_failures) =
+            spawn_heartbeat(&ws_sink, &last_sequence, &heartbeat, heartbeat_interval);
+
+        let gateway = Self {
+            ws_stream: stream,
+            ws_sink,
+            heartbeat_interval,
+            last_sequence,
+            token,
+            session_id: None,
+            resume_gateway_url: None,
+            url: options.url,
+            intents: options.intents,
+            heartbeat,
+            heartbeat_task,
+            heartbeat_failures,
+            session_retry: None,
+        };
+
+        gateway.identify().await?;
+
+        Ok(gateway)
+    }
+
+    /// Opens a new connection after a disconnect or a `ReconnectRequired`.
+    ///
+    /// If a session is known, this connects to its `resume_gateway_url` and
+    /// resumes it, so missed events are replayed and `Event::Resumed` follows.
+    /// Otherwise it connects to the original URL and identifies again, and
+    /// `Event::Ready` follows.
+    pub async fn reconnect(&mut self) -> Result<(), GatewayError> {
+        let resume = self.resume_data().await;
+        let url = match (&resume, &self.resume_gateway_url) {
+            (Some(_), Some(resume_url)) => versioned(resume_url),
+            _ => self.url.clone(),
+        };
+
+        // Close the old connection with 4000 so Discord keeps the session
+        // resumable; it is often already gone, so a failed send is fine.
+        self.heartbeat_task.abort();
+        let frame = CloseFrame {
+            code: CLOSE_RESUMABLE.into(),
+            reason: "".into(),
+        };
+        let _ = self
+            .ws_sink
+            .lock()
+            .await
+            .send(WsMessage::Close(Some(frame)))
+            .await;
+
+        let (sink, stream, heartbeat_interval) = open(&url).await?;
+
+        self.ws_stream = stream;
+        self.ws_sink = Arc::new(Mutex::new(sink));
+        self.heartbeat_interval = heartbeat_interval;
+        self.session_retry = None;
+        self.heartbeat = Arc::new(std::sync::Mutex::new(Heartbeat::new()));
+        (self.heartbeat_task, self.heartbeat_failures) = spawn_heartbeat(
+            &self.ws_sink,
+            &self.last_sequence,
+            &self.heartbeat,
+            heartbeat_interval,
+        );
+
+        match resume {
+            Some(data) => self.send(&ResumePayload { op: 6, d: data }).await,
+            None => self.identify().await,
+        }
+    }
+
+    /// Whether `reconnect` would resume the session rather than start a new
+    /// one.
+    pub async fn can_resume(&self) -> bool {
+        self.resume_data().await.is_some()
+    }
+
+    async fn resume_data(&self) -> Option<ResumeData> {
+        let seq = (*self.last_sequence.lock().await)?;
+
+        Some(ResumeData {
+            token: self.token.secret().to_string(),
+            session_id: self.session_id.clone()?,
+            seq,
+        })
+    }
+
+    /// Resumes or identifies again once the wait after an invalid session
+    /// is over.
+    async fn retry_session(&mut self) -> Result<(), GatewayError> {
+        let resumable = self
+            .session_retry
+            .take()
+            .is_some_and(|(_, resumable)| resumable);
+
+        match self.resume_data().await {
+            Some(data) if resumable => self.send(&ResumePayload { op: 6, d: data }).await,
+            _ => {
+                self.clear_session().await;
+                self.identify().await
+            }
+        }
+    }
+
+    async fn clear_session(&mut self) {
+        self.session_id = None;
+        *self.last_sequence.lock().await = None;
+    }
+
+    async fn identify(&self) -> Result<(), GatewayError> {
+        let identify = IdentifyPayload {
+            op: 2,
+            d: IdentifyData {
+                token: self.token.secret().to_string(),
+                properties: IdentifyProperties {
+                    os: std::env::consts::OS.to_string(),
+                    browser: "discline".to_string(),
+                    device: "discline".to_string(),
+                },
+                intents: self.intents.bits(),
+            },
+        };
+
+        self.send(&identify).await
+    }
+
+    async fn send<T: Serialize>(&self, payload: &T) -> Result<(), GatewayError> {
+        let text = serde_json::to_string(payload)?;
+
+        self.ws_sink
+            .lock()
+            .await
+            .send(WsMessage::Text(text.into()))
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the next dispatched event. Fails with `HeartbeatTimeout` if
+    /// Discord stopped acknowledging heartbeats, in which case the connection
+    /// is dead and should be replaced with `reconnect`.
+    pub async fn next_event(&mut self) -> Result<Event, GatewayError> {
+        loop {
+            let retry_at = self.session_retry.map(|(at, _)| at);
+
+            let msg = tokio::select! {
+                msg = self.ws_stream.next() => msg,
+                Some(error) = self.heartbeat_failures.recv() => return Err(error),
+                _ = tokio::time::sleep_until(retry_at.unwrap_or_else(tokio::time::Instant::now)),
+                    if retry_at.is_some() =>
+                {
+                    self.retry_session().await?;
+                    continue;
+                }
+            };
+            let Some(msg) = msg else {
+                break;
+            };
+            let msg = msg?;
+
+            if let WsMessage::Close(frame) = &msg {
+                let error = close_error(frame.as_ref().map(|f| u16::from(f.code)));
+
+                // The session cannot be resumed after these, so the next
+                // `reconnect` identifies again.
+                if matches!(
+                    error,
+                    GatewayError::InvalidSequence | GatewayError::SessionTimedOut
+                ) {
+                    self.clear_session().await;
+                }
+
+                return Err(error);
+            }
+
+            if !msg.is_text() {
+                continue;
+            }
+
+            let payload: GatewayPayload = serde_json::from_str(msg.to_text().unwrap())?;
+
+            if let Some(s) = payload.s {
+                *self.last_sequence.lock().await = Some(s);
+            }
+
+            match payload.op {
+                0 => {
+                    if let Some(event_name) = payload.t.as_deref() {
+                        match event_name {
+                            "READY" => {
+                                let ready_data: ReadyData = serde_json::from_value(payload.d)?;
+
+                                self.session_id = Some(ready_data.session_id.clone());
+                                self.resume_gateway_url = ready_data.resume_gateway_url;
+
+                                return Ok(Event::Ready {
+                                    user: ready_data.user,
+                                    guilds: ready_data.guilds,
+                                    session_id: ready_data.session_id,
+                                });
+                            }
+                            // A dispatch that does not decode, e.g. because
+                            // Discord added a field in an unexpected shape,
+                            // is skipped rather than failing the connection.
+                            "MESSAGE_CREATE" => {
+                                if let Ok(message) = serde_json::from_value::<Message>(payload.d) {
+                                    return Ok(Event::MessageCreate(message));
+                                }
+                            }
+                            "INTERACTION_CREATE" => {
+                                if let Ok(interaction) =
+                                    serde_json::from_value::<Box<Interaction>>(payload.d)
+                                {
+                                    return Ok(Event::InteractionCreate(interaction));
+                                }
+                            }
+                            "RESUMED" => return Ok(Event::Resumed),
+                            _ => continue,
+                        }
+                    }
+                }
+                1 => {
+                    let seq = *self.last_sequence.lock().await;
+
+                    let heartbeat_payload = serde_json::json!({
+                        "op": 1,
+                        "d": seq
+                    });
+
+                    let mut sink = self.ws_sink.lock().await;
+
+                    self.heartbeat.lock().unwrap().requested();
+                    sink.send(WsMessage::Text(heartbeat_payload.to_string().into()))
+                        .await?;
+                }
+                11 => {
+                    self.heartbeat.lock().unwrap().acked();
+                }
+                7 => {
+                    return Err(GatewayError::ReconnectRequired);
+                }
+                9 => {
+                    // Invalid session. `d` says whether it can still be
+                    // resumed; Discord asks for a random 1-5 second wait
+                    // before either resuming or identifying again. The wait
+                    // is scheduled so heartbeat ACKs keep being read.
+                    let resumable = payload.d.as_bool().unwrap_or(false);
+                    let delay = Duration::from_millis(rand::rng().random_range(1000..=5000));
+
+                    self.session_retry = Some((tokio::time::Instant::now() + delay, resumable));
+                }
+                _ => continue,
+            }
+        }
+
+        Err(GatewayError::UnexpectedClose)
+    }
+
+    pub fn heartbeat_interval(&self) -> Duration {
+        self.heartbeat_interval
+    }
+
+    pub fn intents(&self) -> Intents {
+        self.intents
+    }
+
+    /// Stops heartbeating and sends a close frame with `code`, e.g. 1000 to
+    /// end the session or 4000 to keep it resumable.
+    pub async fn close(self, code: u16) -> Result<(), GatewayError> {
+        self.heartbeat_task.abort();
+
+        let frame = CloseFrame {
+            code: code.into(),
+            reason: "".into(),
+        };
+
+        self.ws_sink
+            .lock()
+            .await
+            .send(WsMessage::Close(Some(frame)))
+            .await?;
+
+        Ok(())
+    }
+
+    /// The round-trip time of the last acknowledged heartbeat, or `None`
+    /// before the first ACK on this connection.
+    pub fn latency(&self) -> Option<Duration> {
+        self.heartbeat.lock().unwrap().latency
+    }
+}
+
+impl Drop for Gateway {
+    fn drop(&mut self) {
+        self.heartbeat_task.abort();
+    }
+}
+
+/// The error a close frame stands for, by its close code.
+fn close_error(code: Option<u16>) -> GatewayError {
+    match code {
+        Some(CLOSE_AUTHENTICATION_FAILED) => GatewayError::AuthenticationFailed,
+        Some(CLOSE_INVALID_SEQUENCE) => GatewayError::InvalidSequence,
+        Some(CLOSE_SESSION_TIMED_OUT) => GatewayError::SessionTimedOut,
+        Some(CLOSE_INVALID_SHARD) => GatewayError::InvalidShard,
+        Some(CLOSE_SHARDING_REQUIRED) => GatewayError::ShardingRequired,
+        Some(CLOSE_INVALID_API_VERSION) => GatewayError::InvalidApiVersion,
+        Some(CLOSE_INVALID_INTENTS) => GatewayError::InvalidIntents,
+        Some(CLOSE_DISALLOWED_INTENTS) => GatewayError::DisallowedIntents,
+        _ => GatewayError::UnexpectedClose,
+    }
+}
+
+/// Connects and waits for HELLO, returning the heartbeat interval it gives.
+async fn open(url: &str) -> Result<(WsSink, SplitStream<WsStream>, Duration), GatewayError> {
+    let (ws, _) = connect_async(url).await?;
+    let (sink, mut stream) = ws.split();
+
+    let hello_msg = stream.next().await.ok_or(GatewayError::UnexpectedClose)??;
+    let payload: GatewayPayload = serde_json::from_str(
+        hello_msg
+            .to_text()
+            .map_err(|_| GatewayError::ProtocolError("Invalid UTF-8".into()))?,
+    )?;
+
+    if payload.op != 10 {
+        return Err(GatewayError::ProtocolError(format!(
+            "Expected HELLO (10), got {}",
+            payload.op
+        )));
+    }
+
+    let hello_data: HelloData = serde_json::from_value(payload.d)?;
+
+    Ok((
+        sink,
+        stream,
+        Duration::from_millis(hello_data.heartbeat_interval),
+    ))
+}
+
+/// Sends a heartbeat every `interval`, the first one after a random fraction
+/// of it as Discord requires. If the previous heartbeat was not acknowledged
+/// by the time the next one is due, the connection is considered a zombie: the
+/// task reports `HeartbeatTimeout` on the returned channel and stops. A failed
+/// send is reported the same way.
+fn spawn_heartbeat(
+    sink: &Arc<Mutex<WsSink>>,
+    sequence: &Arc<Mutex<Option<u64>>>,
+    heartbeat: &Arc<std::sync::Mutex<Heartbeat>>,
+    interval: Duration,
+) -> (JoinHandle<()>, mpsc::Receiver<GatewayError>) {
+    let heartbeat_sink = Arc::clone(sink);
+    let heartbeat_seq = Arc::clone(sequence);
+    let heartbeat = Arc::clone(heartbeat);
+    let (failures, failures_rx) = mpsc::channel(1);
+
+    let task = tokio::spawn(async move {
+        let jitter = rand::rng().random_range(0.0..1.0);
+        let first = tokio::time::Instant::now() + interval.mul_f64(jitter);
+        let mut timer = tokio::time::interval_at(first, interval);
+
+        loop {
+            timer.tick().await;
+
+            let acked = heartbeat.lock().unwrap().acked;
+
+            if !acked {
+                let _ = failures.send(GatewayError::HeartbeatTimeout).await;
+                break;
+            }
+
+            let seq = *heartbeat_seq.lock().await;
+
+            let heartbeat_payload = serde_json::json!({
+                "op": 1,
+                "d": seq
+            });
+
+            let mut sink = heartbeat_sink.lock().await;
+
+            heartbeat.lock().unwrap().sent();
+
+            if let Err(e) = sink
+                .send(WsMessage::Text(heartbeat_payload.to_string().into()))
+                .await
+            {
+                let _ = failures.send(e.into()).await;
+                break;
+            }
+        }
+    });
+
+    (task, failures_rx)
+}
+
+/// `resume_gateway_url` comes without the version and encoding parameters.
+fn versioned(url: &str) -> String {
+    if url.contains('?') {
+        url.to_string()
+    } else {
+        format!("{}/?v=10&encoding=json", url.trim_end_matches('/'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_versioned_resume_url() {
+        assert_eq!(
+            versioned("wss://gateway-us-east1-b.discord.gg"),
+            "wss://gateway-us-east1-b.discord.gg/?v=10&encoding=json"
+        );
+        assert_eq!(
+            versioned("wss://gateway.discord.gg/?v=10&encoding=json"),
+            "wss://gateway.discord.gg/?v=10&encoding=json"
+        );
+    }
+
+    #[test]
+    fn test_heartbeat_ack_measures_latency() {
+        let mut heartbeat = Heartbeat::new();
+
+        assert!(heartbeat.acked);
+        assert_eq!(heartbeat.latency, None);
+
+        heartbeat.sent();
+        assert!(!heartbeat.acked);
+
+        heartbeat.acked();
+        assert!(heartbeat.acked);
+        assert!(heartbeat.latency.is_some());
+    }
+
+    #[test]
+    fn test_requested_heartbeat_keeps_missed_ack_outstanding() {
+        let mut heartbeat = Heartbeat::new();
+
+        heartbeat.sent();
+        let sent_at = heartbeat.sent_at;
+
+        heartbeat.requested();
+        assert!(!heartbeat.acked);
+        assert_eq!(heartbeat.sent_at, sent_at);
+
+        heartbeat.acked();
+        heartbeat.requested();
+        assert!(!heartbeat.acked);
+    }
+}
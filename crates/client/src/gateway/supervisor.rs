@@ -0,0 +1,199 @@
+use rand::RngExt;
+use std::time::Duration;
+use tokio::time::sleep;
+
+use super::{Event, Gateway, GatewayOptions};
+use crate::{auth::Token, errors::gateway::GatewayError};
+
+/// Where a `GatewaySupervisor` is in its connection lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Opening a new session.
+    Connecting,
+    /// The socket is open and identified or resumed.
+    Connected,
+    /// Reconnecting to resume the previous session.
+    Resuming,
+    /// The connection was lost; a reconnect follows after a backoff.
+    Disconnected { reason: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Idle,
+    Connecting,
+    Running,
+}
+
+/// Owns a `Gateway` and keeps it connected.
+///
+/// `next_event` yields the gateway's events interleaved with
+/// `Event::ConnectionState` updates. When the connection drops, the
+/// supervisor reconnects with jittered exponential backoff, resuming the
+/// session when Discord allows it. Only fatal errors, such as an invalid
+/// token, are returned as `Err`.
+pub struct GatewaySupervisor {
+    token: Token,
+    options: GatewayOptions,
+    gateway: Option<Gateway>,
+    status: Status,
+    attempt: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl GatewaySupervisor {
+    const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+    const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+    pub fn new(token: Token) -> Self {
+        Self::with_options(token, GatewayOptions::default())
+    }
+
+    pub fn with_url(token: Token, url: &str) -> Self {
+        Self::with_options(token, GatewayOptions::default().with_url(url))
+    }
+
+    pub fn with_options(token: Token, options: GatewayOptions) -> Self {
+        Self {
+            token,
+            options,
+            gateway: None,
+            status: Status::Idle,
+            attempt: 0,
+            initial_backoff: Self::INITIAL_BACKOFF,
+            max_backoff: Self::MAX_BACKOFF,
+        }
+    }
+
+    /// Sets the delay before the first retry and the cap it doubles up to.
+    pub fn set_backoff(&mut self, initial: Duration, max: Duration) {
+        self.initial_backoff = initial;
+        self.max_backoff = max;
+    }
+
+    /// The current connection, if one has been established.
+    pub fn gateway(&self) -> Option<&Gateway> {
+        self.gateway.as_ref()
+    }
+
+    /// Closes the current connection, if any, with `code`. The next call to
+    /// `next_event` starts over with a new session.
+    pub async fn close(&mut self, code: u16) -> Result<(), GatewayError> {
+        self.status = Status::Idle;
+        self.attempt = 0;
+
+        match self.gateway.take() {
+            Some(gateway) => gateway.close(code).await,
+            None => Ok(()),
+        }
+    }
+
+    pub async fn next_event(&mut self) -> Result<Event, GatewayError> {
+        match self.status {
+            Status::Idle => {
+                let resuming = match &self.gateway {
+                    Some(gateway) => gateway.can_resume().await,
+                    None => false,
+                };
+
+                self.status = Status::Connecting;
+
+                Ok(Event::ConnectionState(if resuming {
+                    ConnectionState::Resuming
+                } else {
+                    ConnectionState::Connecting
+                }))
+            }
+            Status::Connecting => {
+                if self.attempt > 0 {
+                    self.wait().await;
+                }
+
+                match self.connect().await {
+                    Ok(()) => {
+                        self.status = Status::Running;
+                        Ok(Event::ConnectionState(ConnectionState::Connected))
+                    }
+                    Err(error) => self.disconnected(error),
+                }
+            }
+            Status::Running => {
+                let gateway = self
+                    .gateway
+                    .as_mut()
+                    .expect("a running supervisor has a gateway");
+
+                match gateway.next_event().await {
+                    Ok(event) => {
+                        // Only a completed handshake counts as a healthy
+                        // connection, so sockets that drop right away keep
+                        // backing off.
+                        if matches!(event, Event::Ready { .. } | Event::Resumed) {
+                            self.attempt = 0;
+                        }
+
+                        Ok(event)
+                    }
+                    Err(error) => self.disconnected(error),
+                }
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<(), GatewayError> {
+        match &mut self.gateway {
+            Some(gateway) => gateway.reconnect().await,
+            None => {
+                let gateway =
+                    Gateway::connect_with_options(self.token.clone(), self.options.clone()).await?;
+                self.gateway = Some(gateway);
+                Ok(())
+            }
+        }
+    }
+
+    fn disconnected(&mut self, error: GatewayError) -> Result<Event, GatewayError> {
+        self.status = Status::Idle;
+
+        if error.is_fatal() {
+            return Err(error);
+        }
+
+        self.attempt += 1;
+
+        Ok(Event::ConnectionState(ConnectionState::Disconnected {
+            reason: error.to_string(),
+        }))
+    }
+
+    async fn wait(&self) {
+        let backoff = backoff(self.attempt, self.initial_backoff, self.max_backoff);
+        let jitter = rand::rng().random_range(0..=backoff.as_millis() as u64 / 4);
+
+        sleep(backoff + Duration::from_millis(jitter)).await;
+    }
+}
+
+/// `initial` doubled for every attempt after the first, capped at `max`.
+fn backoff(attempt: u32, initial: Duration, max: Duration) -> Duration {
+    let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+
+    initial.saturating_mul(factor).min(max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let initial = Duration::from_secs(1);
+        let max = Duration::from_secs(60);
+
+        assert_eq!(backoff(1, initial, max), Duration::from_secs(1));
+        assert_eq!(backoff(3, initial, max), Duration::from_secs(4));
+        assert_eq!(backoff(7, initial, max), Duration::from_secs(60));
+        assert_eq!(backoff(40, initial, max), Duration::from_secs(60));
+    }
+}
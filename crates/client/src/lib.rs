@@ -1,5 +1,15 @@
+pub mod auth;
+pub mod bodies;
 pub mod client;
 pub mod errors;
+pub mod files;
 pub mod gateway;
+pub mod pagination;
 pub mod queries;
+pub mod ratelimit;
+pub mod request;
+pub mod routes;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 pub mod traits;
+pub mod webhook;
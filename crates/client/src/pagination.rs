@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, stream};
+use std::collections::VecDeque;
+use types::{
+    channel::ChannelId,
+    message::{Message, MessageId},
+};
+
+use crate::{
+    errors::client::ClientError, queries::GetMessagesQuery, traits::rest_client::RestClient,
+};
+
+const PAGE_SIZE: usize = 100;
+
+/// Where a history walk begins and which way it pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryStart {
+    /// Pages backwards from the newest message in the channel.
+    Latest,
+    /// Pages backwards from (excluding) the given message.
+    Before(MessageId),
+    /// Pages forwards from (excluding) the given message.
+    After(MessageId),
+}
+
+/// When a history walk stops, besides reaching the end of the channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryBound {
+    Unbounded,
+    /// Stops after yielding this many messages.
+    Count(usize),
+    /// Stops when reaching this message, which is not yielded.
+    Message(MessageId),
+    /// Stops at the first message older (backwards) or newer (forwards) than
+    /// this timestamp.
+    Timestamp(DateTime<Utc>),
+}
+
+struct HistoryState<'a, C> {
+    client: &'a C,
+    channel_id: ChannelId,
+    start: HistoryStart,
+    bound: HistoryBound,
+    buffer: VecDeque<Message>,
+    yielded: usize,
+    exhausted: bool,
+}
+
+impl<C: RestClient> HistoryState<'_, C> {
+    fn forward(&self) -> bool {
+        matches!(self.start, HistoryStart::After(_))
+    }
+
+    fn count_reached(&self) -> bool {
+        matches!(self.bound, HistoryBound::Count(count) if self.yielded >= count)
+    }
+
+    fn within_bound(&self, message: &Message) -> bool {
+        match self.bound {
+            HistoryBound::Unbounded => true,
+            HistoryBound::Count(_) => !self.count_reached(),
+            HistoryBound::Message(id) if self.forward() => message.id < id,
+            HistoryBound::Message(id) => message.id > id,
+            HistoryBound::Timestamp(at) if self.forward() => message.timestamp <= at,
+            HistoryBound::Timestamp(at) => message.timestamp >= at,
+        }
+    }
+
+    async fn fetch_page(&mut self) -> Result<(), ClientError> {
+        let limit = match self.bound {
+            HistoryBound::Count(count) => count.saturating_sub(self.yielded).min(PAGE_SIZE),
+            _ => PAGE_SIZE,
+        };
+
+        let mut query = GetMessagesQuery::with_limit(limit as u8);
+
+        match self.start {
+            HistoryStart::Latest => {}
+            HistoryStart::Before(id) => query.before = Some(id),
+            HistoryStart::After(id) => query.after = Some(id),
+        }
+
+        let mut page = self.client.get_messages(self.channel_id, query).await?;
+
+        if self.forward() {
+            page.sort_by_key(|m| m.id);
+        } else {
+            page.sort_by_key(|m| std::cmp::Reverse(m.id));
+        }
+
+        self.exhausted = page.len() < limit;
+
+        if let Some(last) = page.last() {
+            self.start = if self.forward() {
+                HistoryStart::After(last.id)
+            } else {
+                HistoryStart::Before(last.id)
+            };
+        }
+
+        self.buffer.extend(page);
+
+        Ok(())
+    }
+}
+
+/// Streams a channel's history one message at a time, fetching pages of up
+/// to 100 messages as needed. Requests go through `client`, so an
+/// `HttpClient` applies its usual rate-limit handling between pages.
+///
+/// The stream ends after the first error.
+pub fn message_history<C: RestClient + Sync>(
+    client: &C,
+    channel_id: ChannelId,
+    start: HistoryStart,
+    bound: HistoryBound,
+) -> impl Stream<Item = Result<Message, ClientError>> + Send + '_ {
+    let state = HistoryState {
+        client,
+        channel_id,
+        start,
+        bound,
+        buffer: VecDeque::new(),
+        yielded: 0,
+        exhausted: false,
+    };
+
+    stream::unfold(Some(state), |state| async move {
+        let mut state = state?;
+
+        loop {
+            if let Some(message) = state.buffer.pop_front() {
+                if !state.within_bound(&message) {
+                    return None;
+                }
+
+                state.yielded += 1;
+                return Some((Ok(message), Some(state)));
+            }
+
+            if state.exhausted || state.count_reached() {
+                return None;
+            }
+
+            if let Err(e) = state.fetch_page().await {
+                return Some((Err(e), None));
+            }
+        }
+    })
+}
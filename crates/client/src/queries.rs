@@ -1,5 +1,6 @@
+use chrono::{DateTime, Utc};
 use serde::Serialize;
-use types::message::MessageId;
+use types::{channel::ChannelId, guild::GuildId, message::MessageId, user::UserId};
 
 #[derive(Debug, Serialize, Default, Clone, Copy)]
 pub struct GetMessagesQuery {
@@ -21,3 +22,69 @@ impl GetMessagesQuery {
         }
     }
 }
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct GetReactionsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>,
+}
+
+impl GetReactionsQuery {
+    pub fn with_limit(limit: u8) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct GetArchivedThreadsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>,
+}
+
+impl GetArchivedThreadsQuery {
+    pub fn with_limit(limit: u8) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct WebhookQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<ChannelId>,
+}
+
+/// Pages through the current user's guilds in ID order. Discord returns at
+/// most 200 per request.
+#[derive(Debug, Serialize, Default, Clone, Copy)]
+pub struct GetCurrentUserGuildsQuery {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<GuildId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<GuildId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u8>,
+    /// Includes the approximate member and presence counts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_counts: Option<bool>,
+}
+
+impl GetCurrentUserGuildsQuery {
+    pub fn with_limit(limit: u8) -> Self {
+        Self {
+            limit: Some(limit),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use reqwest::{Response, header::HeaderMap};
+use serde::Deserialize;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::{
+    sync::Mutex,
+    time::{Instant, sleep_until},
+};
+
+const BUCKET_HEADER: &str = "x-ratelimit-bucket";
+const REMAINING_HEADER: &str = "x-ratelimit-remaining";
+const RESET_AFTER_HEADER: &str = "x-ratelimit-reset-after";
+const GLOBAL_HEADER: &str = "x-ratelimit-global";
+const SCOPE_HEADER: &str = "x-ratelimit-scope";
+const RETRY_AFTER_HEADER: &str = "retry-after";
+
+/// Tracks Discord's per-route rate-limit buckets so requests can be delayed
+/// before they would be rejected with a 429.
+///
+/// Cloning a `RateLimiter` shares the underlying bucket table.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<RateLimitState>>,
+}
+
+#[derive(Debug, Default)]
+struct RateLimitState {
+    routes: HashMap<String, String>,
+    buckets: HashMap<String, Bucket>,
+    global_reset: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    remaining: u32,
+    reset_at: Instant,
+}
+
+/// Identifies a route for rate-limit purposes: the method, the path with
+/// minor IDs collapsed and the major parameter (channel, guild or webhook).
+/// Built by `Route::key`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RouteKey {
+    pub route: String,
+    pub major: String,
+}
+
+/// The details of a 429 response, read from its JSON body when present and
+/// from the `Retry-After` and `X-RateLimit-*` headers otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitInfo {
+    pub retry_after: Duration,
+    pub global: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RateLimitBody {
+    retry_after: f64,
+    #[serde(default)]
+    global: bool,
+}
+
+impl RateLimitInfo {
+    const DEFAULT_RETRY_AFTER: f64 = 1.0;
+
+    pub async fn from_response(response: Response) -> Self {
+        let headers = response.headers();
+        let header = |name: &str| headers.get(name).and_then(|h| h.to_str().ok());
+
+        let header_global =
+            header(GLOBAL_HEADER) == Some("true") || header(SCOPE_HEADER) == Some("global");
+        let header_retry_after = header(RETRY_AFTER_HEADER).and_then(|s| s.parse::<f64>().ok());
+
+        let (retry_after, global) = match response.json::<RateLimitBody>().await {
+            Ok(body) => (body.retry_after, body.global || header_global),
+            Err(_) => (
+                header_retry_after.unwrap_or(Self::DEFAULT_RETRY_AFTER),
+                header_global,
+            ),
+        };
+
+        Self {
+            retry_after: Duration::from_secs_f64(retry_after.max(0.0)),
+            global,
+        }
+    }
+}
+
+impl RateLimiter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Waits until the bucket for `key` (and the global limit) allows another
+    /// request, then reserves a slot in it.
+    pub async fn acquire(&self, key: &RouteKey) {
+        loop {
+            let wait_until = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+
+                match state.global_reset {
+                    Some(reset) if reset > now => Some(reset),
+                    _ => {
+                        let bucket_id = state
+                            .routes
+                            .get(&key.route)
+                            .cloned()
+                            .unwrap_or_else(|| key.route.clone());
+                        let bucket_id = format!("{}:{}", bucket_id, key.major);
+
+                        match state.buckets.get_mut(&bucket_id) {
+                            Some(bucket) if bucket.reset_at > now => {
+                                if bucket.remaining > 0 {
+                                    bucket.remaining -= 1;
+                                    None
+                                } else {
+                                    Some(bucket.reset_at)
+                                }
+                            }
+                            Some(_) => {
+                                state.buckets.remove(&bucket_id);
+                                None
+                            }
+                            None => None,
+                        }
+                    }
+                }
+            };
+
+            match wait_until {
+                Some(instant) => sleep_until(instant).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Records the rate-limit headers Discord returned for `key`.
+    pub async fn update(&self, key: &RouteKey, headers: &HeaderMap) {
+        let header = |name: &str| headers.get(name).and_then(|h| h.to_str().ok());
+
+        let remaining = header(REMAINING_HEADER).and_then(|s| s.parse::<u32>().ok());
+        let reset_after = header(RESET_AFTER_HEADER).and_then(|s| s.parse::<f64>().ok());
+
+        let (Some(remaining), Some(reset_after)) = (remaining, reset_after) else {
+            return;
+        };
+
+        let mut state = self.state.lock().await;
+        let reset_at = Instant::now() + Duration::from_secs_f64(reset_after.max(0.0));
+
+        if header(GLOBAL_HEADER) == Some("true") {
+            state.global_reset = Some(reset_at);
+            return;
+        }
+
+        let bucket_hash = header(BUCKET_HEADER)
+            .map(str::to_string)
+            .unwrap_or_else(|| key.route.clone());
+
+        state.routes.insert(key.route.clone(), bucket_hash.clone());
+        state.buckets.insert(
+            format!("{}:{}", bucket_hash, key.major),
+            Bucket {
+                remaining,
+                reset_at,
+            },
+        );
+    }
+
+    /// Applies a 429 so that every request on the bucket, or on every route
+    /// when the limit is global, waits until it has passed.
+    pub async fn block(&self, key: &RouteKey, info: RateLimitInfo) {
+        let mut state = self.state.lock().await;
+        let reset_at = Instant::now() + info.retry_after;
+
+        if info.global {
+            state.global_reset = Some(reset_at);
+            return;
+        }
+
+        let bucket_hash = state
+            .routes
+            .get(&key.route)
+            .cloned()
+            .unwrap_or_else(|| key.route.clone());
+
+        state.buckets.insert(
+            format!("{}:{}", bucket_hash, key.major),
+            Bucket {
+                remaining: 0,
+                reset_at,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::Route;
+    use reqwest::header::HeaderValue;
+    use types::{channel::ChannelId, guild::GuildId, message::MessageId, webhook::WebhookId};
+
+    fn headers(remaining: &str, reset_after: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(BUCKET_HEADER, HeaderValue::from_static("abcd"));
+        headers.insert(REMAINING_HEADER, HeaderValue::from_str(remaining).unwrap());
+        headers.insert(
+            RESET_AFTER_HEADER,
+            HeaderValue::from_str(reset_after).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn test_route_key_keeps_major_parameter() {
+        let key = Route::DeleteMessage {
+            channel_id: ChannelId(123),
+            message_id: MessageId(456),
+        }
+        .key();
+
+        assert_eq!(key.route, "DELETE /channels/{major}/messages/{id}");
+        assert_eq!(key.major, "123");
+    }
+
+    #[test]
+    fn test_route_key_webhook_major_hides_token() {
+        let key = Route::ExecuteWebhook {
+            webhook_id: WebhookId(1),
+            token: "secret",
+        }
+        .key();
+
+        assert_eq!(key.route, "POST /webhooks/{major}/{major}");
+        assert!(key.major.starts_with("1/"));
+        assert!(!key.major.contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_exhausted_bucket() {
+        let limiter = RateLimiter::new();
+        let key = Route::GetMessages {
+            channel_id: ChannelId(1),
+        }
+        .key();
+
+        limiter.update(&key, &headers("0", "0.2")).await;
+
+        let start = std::time::Instant::now();
+        limiter.acquire(&key).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_global_block_delays_every_route() {
+        let limiter = RateLimiter::new();
+        let limited = Route::GetMessages {
+            channel_id: ChannelId(1),
+        }
+        .key();
+        let other = Route::GetGuildChannels {
+            guild_id: GuildId(2),
+        }
+        .key();
+
+        limiter
+            .block(
+                &limited,
+                RateLimitInfo {
+                    retry_after: Duration::from_millis(200),
+                    global: true,
+                },
+            )
+            .await;
+
+        let start = std::time::Instant::now();
+        limiter.acquire(&other).await;
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_buckets_are_separate_per_major_parameter() {
+        let limiter = RateLimiter::new();
+        let limited = Route::GetMessages {
+            channel_id: ChannelId(1),
+        }
+        .key();
+        let other = Route::GetMessages {
+            channel_id: ChannelId(2),
+        }
+        .key();
+
+        limiter.update(&limited, &headers("0", "10")).await;
+
+        let start = std::time::Instant::now();
+        limiter.acquire(&other).await;
+
+        assert!(start.elapsed() < Duration::from_millis(100));
+    }
+}
@@ -0,0 +1,244 @@
+use reqwest::{
+    RequestBuilder,
+    header::{HeaderMap, HeaderName, HeaderValue},
+    multipart::Form,
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::{
+    errors::client::ClientError,
+    files::FileUpload,
+    routes::{Route, percent_encode},
+};
+
+const AUDIT_LOG_REASON_HEADER: &str = "x-audit-log-reason";
+
+#[derive(Debug, Clone)]
+enum RequestBody<'a> {
+    Empty,
+    Json(Value),
+    Multipart {
+        payload_json: String,
+        files: &'a [FileUpload],
+    },
+}
+
+/// A single REST call: the route plus an optional body, query, extra headers
+/// and timeout.
+///
+/// Bodies are serialized when they are attached, so any `Serialize` value
+/// works and retries resend the same bytes. Serialization errors are kept
+/// and returned by `HttpClient::send`.
+#[derive(Debug)]
+pub struct Request<'a> {
+    route: Route<'a>,
+    body: RequestBody<'a>,
+    query: Vec<(String, String)>,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    error: Option<ClientError>,
+}
+
+impl<'a> Request<'a> {
+    pub fn new(route: Route<'a>) -> Self {
+        Self {
+            route,
+            body: RequestBody::Empty,
+            query: Vec::new(),
+            headers: HeaderMap::new(),
+            timeout: None,
+            error: None,
+        }
+    }
+
+    pub fn route(&self) -> Route<'a> {
+        self.route
+    }
+
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        match serde_json::to_value(body) {
+            Ok(value) => self.body = RequestBody::Json(value),
+            Err(e) => self.fail(ClientError::ParseError(e.to_string())),
+        }
+
+        self
+    }
+
+    /// Sends `payload` as `payload_json` and each file as `files[n]`, with a
+    /// matching `attachments` array injected into the payload.
+    pub fn multipart<B: Serialize + ?Sized>(
+        mut self,
+        payload: &B,
+        files: &'a [FileUpload],
+    ) -> Self {
+        let mut payload = match serde_json::to_value(payload) {
+            Ok(value) => value,
+            Err(e) => {
+                self.fail(ClientError::ParseError(e.to_string()));
+                return self;
+            }
+        };
+
+        if let Some(object) = payload.as_object_mut() {
+            let attachments = files
+                .iter()
+                .enumerate()
+                .map(|(i, file)| serde_json::json!({ "id": i, "filename": file.filename }))
+                .collect();
+
+            object.insert("attachments".into(), Value::Array(attachments));
+        }
+
+        self.body = RequestBody::Multipart {
+            payload_json: payload.to_string(),
+            files,
+        };
+
+        self
+    }
+
+    /// Adds query parameters from a struct that serializes to a flat object.
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        match serde_json::to_value(query) {
+            Ok(Value::Object(map)) => {
+                for (key, value) in map {
+                    match value {
+                        Value::Null => {}
+                        Value::String(s) => self.query.push((key, s)),
+                        other => self.query.push((key, other.to_string())),
+                    }
+                }
+            }
+            Ok(Value::Null) => {}
+            Ok(_) => self.fail(ClientError::ParseError(
+                "Query must serialize to an object".to_string(),
+            )),
+            Err(e) => self.fail(ClientError::ParseError(e.to_string())),
+        }
+
+        self
+    }
+
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sets the `X-Audit-Log-Reason` header shown in the guild's audit log.
+    pub fn reason(self, reason: &str) -> Self {
+        let value = HeaderValue::from_str(&percent_encode(reason))
+            .expect("percent-encoded text is a valid header value");
+
+        self.header(HeaderName::from_static(AUDIT_LOG_REASON_HEADER), value)
+    }
+
+    /// Overrides the client's timeout for every attempt of this request.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn fail(&mut self, error: ClientError) {
+        self.error.get_or_insert(error);
+    }
+
+    pub(crate) fn take_error(&mut self) -> Option<ClientError> {
+        self.error.take()
+    }
+
+    /// Applies the request to a fresh builder. Called once per attempt.
+    pub(crate) fn apply(&self, mut builder: RequestBuilder) -> Result<RequestBuilder, ClientError> {
+        if !self.query.is_empty() {
+            builder = builder.query(&self.query);
+        }
+
+        if !self.headers.is_empty() {
+            builder = builder.headers(self.headers.clone());
+        }
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        Ok(match &self.body {
+            RequestBody::Empty => builder,
+            RequestBody::Json(value) => builder.json(value),
+            RequestBody::Multipart {
+                payload_json,
+                files,
+            } => {
+                let mut form = Form::new().text("payload_json", payload_json.clone());
+
+                for (i, file) in files.iter().enumerate() {
+                    form = form.part(format!("files[{}]", i), file.to_part()?);
+                }
+
+                builder.multipart(form)
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::Client;
+    use types::channel::ChannelId;
+
+    fn route() -> Route<'static> {
+        Route::GetMessages {
+            channel_id: ChannelId(1),
+        }
+    }
+
+    #[test]
+    fn test_query_skips_missing_values() {
+        let request = Request::new(route())
+            .query(&serde_json::json!({ "limit": 50, "before": null, "after": "7" }));
+
+        assert_eq!(
+            request.query,
+            vec![
+                ("after".to_string(), "7".to_string()),
+                ("limit".to_string(), "50".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_query_rejects_non_object() {
+        let mut request = Request::new(route()).query(&[1, 2, 3]);
+
+        assert!(matches!(
+            request.take_error(),
+            Some(ClientError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_reason_is_percent_encoded() {
+        let request = Request::new(route()).reason("Spam cleanup ✔");
+
+        assert_eq!(
+            request.headers[AUDIT_LOG_REASON_HEADER],
+            "Spam%20cleanup%20%E2%9C%94"
+        );
+    }
+
+    #[test]
+    fn test_apply_sets_json_body() {
+        let request = Request::new(route()).json(&serde_json::json!({ "content": "hi" }));
+        let built = request
+            .apply(Client::new().post("http://localhost/"))
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            built.body().unwrap().as_bytes().unwrap(),
+            br#"{"content":"hi"}"#
+        );
+    }
+}
@@ -0,0 +1,762 @@
+use reqwest::Method;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use types::{
+    channel::ChannelId,
+    command::{ApplicationId, CommandId},
+    emoji::ReactionType,
+    guild::GuildId,
+    interaction::InteractionId,
+    message::MessageId,
+    user::UserId,
+    webhook::WebhookId,
+};
+
+use crate::ratelimit::RouteKey;
+
+/// Every REST endpoint the client calls, with its path parameters.
+///
+/// A route knows its HTTP method, its path, the rate-limit bucket it falls
+/// into and the resource it addresses, so none of that has to be rebuilt
+/// from a formatted string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route<'a> {
+    GetCurrentUser,
+    GetCurrentUserGuilds,
+    GetGuildChannels {
+        guild_id: GuildId,
+    },
+    GetMessages {
+        channel_id: ChannelId,
+    },
+    CreateMessage {
+        channel_id: ChannelId,
+    },
+    EditMessage {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    DeleteMessage {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    BulkDeleteMessages {
+        channel_id: ChannelId,
+    },
+    AddReaction {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &'a ReactionType,
+    },
+    RemoveOwnReaction {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &'a ReactionType,
+    },
+    RemoveUserReaction {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &'a ReactionType,
+        user_id: UserId,
+    },
+    GetReactions {
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &'a ReactionType,
+    },
+    GetPins {
+        channel_id: ChannelId,
+    },
+    PinMessage {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    UnpinMessage {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    StartThreadFromMessage {
+        channel_id: ChannelId,
+        message_id: MessageId,
+    },
+    StartThread {
+        channel_id: ChannelId,
+    },
+    GetActiveThreads {
+        guild_id: GuildId,
+    },
+    GetPublicArchivedThreads {
+        channel_id: ChannelId,
+    },
+    GetPrivateArchivedThreads {
+        channel_id: ChannelId,
+    },
+    JoinThread {
+        thread_id: ChannelId,
+    },
+    LeaveThread {
+        thread_id: ChannelId,
+    },
+    ExecuteWebhook {
+        webhook_id: WebhookId,
+        token: &'a str,
+    },
+    EditWebhookMessage {
+        webhook_id: WebhookId,
+        token: &'a str,
+        message_id: MessageId,
+    },
+    DeleteWebhookMessage {
+        webhook_id: WebhookId,
+        token: &'a str,
+        message_id: MessageId,
+    },
+    GetGlobalCommands {
+        application_id: ApplicationId,
+    },
+    CreateGlobalCommand {
+        application_id: ApplicationId,
+    },
+    BulkOverwriteGlobalCommands {
+        application_id: ApplicationId,
+    },
+    DeleteGlobalCommand {
+        application_id: ApplicationId,
+        command_id: CommandId,
+    },
+    GetGuildCommands {
+        application_id: ApplicationId,
+        guild_id: GuildId,
+    },
+    CreateGuildCommand {
+        application_id: ApplicationId,
+        guild_id: GuildId,
+    },
+    BulkOverwriteGuildCommands {
+        application_id: ApplicationId,
+        guild_id: GuildId,
+    },
+    DeleteGuildCommand {
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        command_id: CommandId,
+    },
+    CreateInteractionResponse {
+        interaction_id: InteractionId,
+        token: &'a str,
+    },
+    EditOriginalResponse {
+        application_id: ApplicationId,
+        token: &'a str,
+    },
+    DeleteOriginalResponse {
+        application_id: ApplicationId,
+        token: &'a str,
+    },
+    CreateFollowupMessage {
+        application_id: ApplicationId,
+        token: &'a str,
+    },
+    EditFollowupMessage {
+        application_id: ApplicationId,
+        token: &'a str,
+        message_id: MessageId,
+    },
+    DeleteFollowupMessage {
+        application_id: ApplicationId,
+        token: &'a str,
+        message_id: MessageId,
+    },
+}
+
+impl Route<'_> {
+    pub fn method(&self) -> Method {
+        match self {
+            Route::GetCurrentUser
+            | Route::GetCurrentUserGuilds
+            | Route::GetGuildChannels { .. }
+            | Route::GetMessages { .. }
+            | Route::GetReactions { .. }
+            | Route::GetPins { .. }
+            | Route::GetActiveThreads { .. }
+            | Route::GetPublicArchivedThreads { .. }
+            | Route::GetPrivateArchivedThreads { .. }
+            | Route::GetGlobalCommands { .. }
+            | Route::GetGuildCommands { .. } => Method::GET,
+            Route::CreateMessage { .. }
+            | Route::BulkDeleteMessages { .. }
+            | Route::StartThreadFromMessage { .. }
+            | Route::StartThread { .. }
+            | Route::ExecuteWebhook { .. }
+            | Route::CreateGlobalCommand { .. }
+            | Route::CreateGuildCommand { .. }
+            | Route::CreateInteractionResponse { .. }
+            | Route::CreateFollowupMessage { .. } => Method::POST,
+            Route::EditMessage { .. }
+            | Route::EditWebhookMessage { .. }
+            | Route::EditOriginalResponse { .. }
+            | Route::EditFollowupMessage { .. } => Method::PATCH,
+            Route::AddReaction { .. }
+            | Route::PinMessage { .. }
+            | Route::JoinThread { .. }
+            | Route::BulkOverwriteGlobalCommands { .. }
+            | Route::BulkOverwriteGuildCommands { .. } => Method::PUT,
+            Route::DeleteMessage { .. }
+            | Route::RemoveOwnReaction { .. }
+            | Route::RemoveUserReaction { .. }
+            | Route::UnpinMessage { .. }
+            | Route::LeaveThread { .. }
+            | Route::DeleteWebhookMessage { .. }
+            | Route::DeleteGlobalCommand { .. }
+            | Route::DeleteGuildCommand { .. }
+            | Route::DeleteOriginalResponse { .. }
+            | Route::DeleteFollowupMessage { .. } => Method::DELETE,
+        }
+    }
+
+    pub fn path(&self) -> String {
+        match self {
+            Route::GetCurrentUser => "/users/@me".to_string(),
+            Route::GetCurrentUserGuilds => "/users/@me/guilds".to_string(),
+            Route::GetGuildChannels { guild_id } => format!("/guilds/{}/channels", guild_id),
+            Route::GetMessages { channel_id } | Route::CreateMessage { channel_id } => {
+                format!("/channels/{}/messages", channel_id)
+            }
+            Route::EditMessage {
+                channel_id,
+                message_id,
+            }
+            | Route::DeleteMessage {
+                channel_id,
+                message_id,
+            } => format!("/channels/{}/messages/{}", channel_id, message_id),
+            Route::BulkDeleteMessages { channel_id } => {
+                format!("/channels/{}/messages/bulk-delete", channel_id)
+            }
+            Route::AddReaction {
+                channel_id,
+                message_id,
+                emoji,
+            }
+            | Route::RemoveOwnReaction {
+                channel_id,
+                message_id,
+                emoji,
+            } => format!(
+                "/channels/{}/messages/{}/reactions/{}/@me",
+                channel_id,
+                message_id,
+                encode_reaction(emoji)
+            ),
+            Route::RemoveUserReaction {
+                channel_id,
+                message_id,
+                emoji,
+                user_id,
+            } => format!(
+                "/channels/{}/messages/{}/reactions/{}/{}",
+                channel_id,
+                message_id,
+                encode_reaction(emoji),
+                user_id
+            ),
+            Route::GetReactions {
+                channel_id,
+                message_id,
+                emoji,
+            } => format!(
+                "/channels/{}/messages/{}/reactions/{}",
+                channel_id,
+                message_id,
+                encode_reaction(emoji)
+            ),
+            Route::GetPins { channel_id } => format!("/channels/{}/pins", channel_id),
+            Route::PinMessage {
+                channel_id,
+                message_id,
+            }
+            | Route::UnpinMessage {
+                channel_id,
+                message_id,
+            } => format!("/channels/{}/pins/{}", channel_id, message_id),
+            Route::StartThreadFromMessage {
+                channel_id,
+                message_id,
+            } => format!("/channels/{}/messages/{}/threads", channel_id, message_id),
+            Route::StartThread { channel_id } => format!("/channels/{}/threads", channel_id),
+            Route::GetActiveThreads { guild_id } => format!("/guilds/{}/threads/active", guild_id),
+            Route::GetPublicArchivedThreads { channel_id } => {
+                format!("/channels/{}/threads/archived/public", channel_id)
+            }
+            Route::GetPrivateArchivedThreads { channel_id } => {
+                format!("/channels/{}/threads/archived/private", channel_id)
+            }
+            Route::JoinThread { thread_id } | Route::LeaveThread { thread_id } => {
+                format!("/channels/{}/thread-members/@me", thread_id)
+            }
+            Route::ExecuteWebhook { webhook_id, token } => {
+                format!("/webhooks/{}/{}", webhook_id, token)
+            }
+            Route::EditWebhookMessage {
+                webhook_id,
+                token,
+                message_id,
+            }
+            | Route::DeleteWebhookMessage {
+                webhook_id,
+                token,
+                message_id,
+            } => format!("/webhooks/{}/{}/messages/{}", webhook_id, token, message_id),
+            Route::GetGlobalCommands { application_id }
+            | Route::CreateGlobalCommand { application_id }
+            | Route::BulkOverwriteGlobalCommands { application_id } => {
+                format!("/applications/{}/commands", application_id)
+            }
+            Route::DeleteGlobalCommand {
+                application_id,
+                command_id,
+            } => format!("/applications/{}/commands/{}", application_id, command_id),
+            Route::GetGuildCommands {
+                application_id,
+                guild_id,
+            }
+            | Route::CreateGuildCommand {
+                application_id,
+                guild_id,
+            }
+            | Route::BulkOverwriteGuildCommands {
+                application_id,
+                guild_id,
+            } => format!(
+                "/applications/{}/guilds/{}/commands",
+                application_id, guild_id
+            ),
+            Route::DeleteGuildCommand {
+                application_id,
+                guild_id,
+                command_id,
+            } => format!(
+                "/applications/{}/guilds/{}/commands/{}",
+                application_id, guild_id, command_id
+            ),
+            Route::CreateInteractionResponse {
+                interaction_id,
+                token,
+            } => format!("/interactions/{}/{}/callback", interaction_id, token),
+            Route::EditOriginalResponse {
+                application_id,
+                token,
+            }
+            | Route::DeleteOriginalResponse {
+                application_id,
+                token,
+            } => format!("/webhooks/{}/{}/messages/@original", application_id, token),
+            Route::CreateFollowupMessage {
+                application_id,
+                token,
+            } => format!("/webhooks/{}/{}", application_id, token),
+            Route::EditFollowupMessage {
+                application_id,
+                token,
+                message_id,
+            }
+            | Route::DeleteFollowupMessage {
+                application_id,
+                token,
+                message_id,
+            } => format!(
+                "/webhooks/{}/{}/messages/{}",
+                application_id, token, message_id
+            ),
+        }
+    }
+
+    /// The path with the major parameter and minor IDs replaced by
+    /// placeholders. Routes that share a template share a rate-limit bucket.
+    pub fn template(&self) -> &'static str {
+        match self {
+            Route::GetCurrentUser => "/users/@me",
+            Route::GetCurrentUserGuilds => "/users/@me/guilds",
+            Route::GetGuildChannels { .. } => "/guilds/{major}/channels",
+            Route::GetMessages { .. } | Route::CreateMessage { .. } => "/channels/{major}/messages",
+            Route::EditMessage { .. } | Route::DeleteMessage { .. } => {
+                "/channels/{major}/messages/{id}"
+            }
+            Route::BulkDeleteMessages { .. } => "/channels/{major}/messages/bulk-delete",
+            Route::AddReaction { .. } | Route::RemoveOwnReaction { .. } => {
+                "/channels/{major}/messages/{id}/reactions/{emoji}/@me"
+            }
+            Route::RemoveUserReaction { .. } => {
+                "/channels/{major}/messages/{id}/reactions/{emoji}/{id}"
+            }
+            Route::GetReactions { .. } => "/channels/{major}/messages/{id}/reactions/{emoji}",
+            Route::GetPins { .. } => "/channels/{major}/pins",
+            Route::PinMessage { .. } | Route::UnpinMessage { .. } => "/channels/{major}/pins/{id}",
+            Route::StartThreadFromMessage { .. } => "/channels/{major}/messages/{id}/threads",
+            Route::StartThread { .. } => "/channels/{major}/threads",
+            Route::GetActiveThreads { .. } => "/guilds/{major}/threads/active",
+            Route::GetPublicArchivedThreads { .. } => "/channels/{major}/threads/archived/public",
+            Route::GetPrivateArchivedThreads { .. } => "/channels/{major}/threads/archived/private",
+            Route::JoinThread { .. } | Route::LeaveThread { .. } => {
+                "/channels/{major}/thread-members/@me"
+            }
+            Route::ExecuteWebhook { .. } => "/webhooks/{major}/{major}",
+            Route::EditWebhookMessage { .. } | Route::DeleteWebhookMessage { .. } => {
+                "/webhooks/{major}/{major}/messages/{id}"
+            }
+            Route::GetGlobalCommands { .. }
+            | Route::CreateGlobalCommand { .. }
+            | Route::BulkOverwriteGlobalCommands { .. } => "/applications/{id}/commands",
+            Route::DeleteGlobalCommand { .. } => "/applications/{id}/commands/{id}",
+            Route::GetGuildCommands { .. }
+            | Route::CreateGuildCommand { .. }
+            | Route::BulkOverwriteGuildCommands { .. } => "/applications/{id}/guilds/{id}/commands",
+            Route::DeleteGuildCommand { .. } => "/applications/{id}/guilds/{id}/commands/{id}",
+            Route::CreateInteractionResponse { .. } => "/interactions/{major}/{major}/callback",
+            Route::EditOriginalResponse { .. } | Route::DeleteOriginalResponse { .. } => {
+                "/webhooks/{major}/{major}/messages/@original"
+            }
+            // Follow-ups go through the application's webhook and share its
+            // buckets.
+            Route::CreateFollowupMessage { .. } => "/webhooks/{major}/{major}",
+            Route::EditFollowupMessage { .. } | Route::DeleteFollowupMessage { .. } => {
+                "/webhooks/{major}/{major}/messages/{id}"
+            }
+        }
+    }
+
+    /// The major parameter Discord scopes the route's rate limit by. Webhook
+    /// and interaction tokens are hashed so they never reach the rate-limit
+    /// table, and with it `HttpClient`'s `Debug` output.
+    pub fn major(&self) -> String {
+        match self {
+            Route::GetGuildChannels { guild_id } | Route::GetActiveThreads { guild_id } => {
+                guild_id.to_string()
+            }
+            Route::GetMessages { channel_id }
+            | Route::CreateMessage { channel_id }
+            | Route::EditMessage { channel_id, .. }
+            | Route::DeleteMessage { channel_id, .. }
+            | Route::BulkDeleteMessages { channel_id }
+            | Route::AddReaction { channel_id, .. }
+            | Route::RemoveOwnReaction { channel_id, .. }
+            | Route::RemoveUserReaction { channel_id, .. }
+            | Route::GetReactions { channel_id, .. }
+            | Route::GetPins { channel_id }
+            | Route::PinMessage { channel_id, .. }
+            | Route::UnpinMessage { channel_id, .. }
+            | Route::StartThreadFromMessage { channel_id, .. }
+            | Route::StartThread { channel_id }
+            | Route::GetPublicArchivedThreads { channel_id }
+            | Route::GetPrivateArchivedThreads { channel_id }
+            | Route::JoinThread {
+                thread_id: channel_id,
+            }
+            | Route::LeaveThread {
+                thread_id: channel_id,
+            } => channel_id.to_string(),
+            Route::ExecuteWebhook { webhook_id, token }
+            | Route::EditWebhookMessage {
+                webhook_id, token, ..
+            }
+            | Route::DeleteWebhookMessage {
+                webhook_id, token, ..
+            } => format!("{}/{}", webhook_id, hash_token(token)),
+            // User and application routes are not scoped by a major parameter.
+            Route::GetCurrentUser
+            | Route::GetCurrentUserGuilds
+            | Route::GetGlobalCommands { .. }
+            | Route::CreateGlobalCommand { .. }
+            | Route::BulkOverwriteGlobalCommands { .. }
+            | Route::DeleteGlobalCommand { .. }
+            | Route::GetGuildCommands { .. }
+            | Route::CreateGuildCommand { .. }
+            | Route::BulkOverwriteGuildCommands { .. }
+            | Route::DeleteGuildCommand { .. } => String::new(),
+            Route::CreateInteractionResponse {
+                interaction_id,
+                token,
+            } => format!("{}/{}", interaction_id, hash_token(token)),
+            Route::EditOriginalResponse {
+                application_id,
+                token,
+            }
+            | Route::DeleteOriginalResponse {
+                application_id,
+                token,
+            }
+            | Route::CreateFollowupMessage {
+                application_id,
+                token,
+            }
+            | Route::EditFollowupMessage {
+                application_id,
+                token,
+                ..
+            }
+            | Route::DeleteFollowupMessage {
+                application_id,
+                token,
+                ..
+            } => format!("{}/{}", application_id, hash_token(token)),
+        }
+    }
+
+    /// Whether the path carries a webhook or interaction token, and so must
+    /// not appear in errors or logs.
+    pub fn has_token(&self) -> bool {
+        matches!(
+            self,
+            Route::ExecuteWebhook { .. }
+                | Route::EditWebhookMessage { .. }
+                | Route::DeleteWebhookMessage { .. }
+                | Route::CreateInteractionResponse { .. }
+                | Route::EditOriginalResponse { .. }
+                | Route::DeleteOriginalResponse { .. }
+                | Route::CreateFollowupMessage { .. }
+                | Route::EditFollowupMessage { .. }
+                | Route::DeleteFollowupMessage { .. }
+        )
+    }
+
+    /// The rate-limit key for this route.
+    pub fn key(&self) -> RouteKey {
+        RouteKey {
+            route: format!("{} {}", self.method(), self.template()),
+            major: self.major(),
+        }
+    }
+
+    /// The type and ID of the resource the route addresses, as reported by
+    /// `ClientError::NotFound` on every 404, with or without an error body.
+    pub fn resource(&self) -> (&'static str, String) {
+        match self {
+            Route::GetCurrentUser | Route::GetCurrentUserGuilds => ("User", "@me".to_string()),
+            Route::GetGuildChannels { guild_id } | Route::GetActiveThreads { guild_id } => {
+                ("Guild", guild_id.to_string())
+            }
+            Route::EditMessage { message_id, .. }
+            | Route::DeleteMessage { message_id, .. }
+            | Route::AddReaction { message_id, .. }
+            | Route::RemoveOwnReaction { message_id, .. }
+            | Route::GetReactions { message_id, .. }
+            | Route::PinMessage { message_id, .. }
+            | Route::UnpinMessage { message_id, .. }
+            | Route::StartThreadFromMessage { message_id, .. }
+            | Route::EditWebhookMessage { message_id, .. }
+            | Route::DeleteWebhookMessage { message_id, .. } => ("Message", message_id.to_string()),
+            Route::ExecuteWebhook { webhook_id, .. } => ("Webhook", webhook_id.to_string()),
+            Route::RemoveUserReaction { user_id, .. } => ("User", user_id.to_string()),
+            Route::JoinThread { thread_id } | Route::LeaveThread { thread_id } => {
+                ("Thread", thread_id.to_string())
+            }
+            Route::GetMessages { channel_id }
+            | Route::CreateMessage { channel_id }
+            | Route::BulkDeleteMessages { channel_id }
+            | Route::GetPins { channel_id }
+            | Route::StartThread { channel_id }
+            | Route::GetPublicArchivedThreads { channel_id }
+            | Route::GetPrivateArchivedThreads { channel_id } => {
+                ("Channel", channel_id.to_string())
+            }
+            Route::GetGlobalCommands { application_id }
+            | Route::CreateGlobalCommand { application_id }
+            | Route::BulkOverwriteGlobalCommands { application_id } => {
+                ("Application", application_id.to_string())
+            }
+            Route::GetGuildCommands { guild_id, .. }
+            | Route::CreateGuildCommand { guild_id, .. }
+            | Route::BulkOverwriteGuildCommands { guild_id, .. } => ("Guild", guild_id.to_string()),
+            Route::DeleteGlobalCommand { command_id, .. }
+            | Route::DeleteGuildCommand { command_id, .. } => ("Command", command_id.to_string()),
+            Route::CreateInteractionResponse { interaction_id, .. } => {
+                ("Interaction", interaction_id.to_string())
+            }
+            Route::CreateFollowupMessage { application_id, .. } => {
+                ("Application", application_id.to_string())
+            }
+            Route::EditOriginalResponse { .. } | Route::DeleteOriginalResponse { .. } => {
+                ("Message", "@original".to_string())
+            }
+            Route::EditFollowupMessage { message_id, .. }
+            | Route::DeleteFollowupMessage { message_id, .. } => {
+                ("Message", message_id.to_string())
+            }
+        }
+    }
+}
+
+/// Identifies a token in a rate-limit key without revealing it.
+fn hash_token(token: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    token.hash(&mut hasher);
+
+    format!("{:016x}", hasher.finish())
+}
+
+/// Percent-encodes everything outside the unreserved URL characters.
+pub(crate) fn percent_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Encodes an emoji for use as a path segment. Custom emoji keep the
+/// `name:id` separator Discord expects.
+fn encode_reaction(emoji: &ReactionType) -> String {
+    match emoji {
+        ReactionType::Unicode(name) => percent_encode(name),
+        ReactionType::Custom { name, id } => format!("{}:{}", percent_encode(name), id),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_reaction_unicode() {
+        let emoji = ReactionType::Unicode("👍".to_string());
+        assert_eq!(encode_reaction(&emoji), "%F0%9F%91%8D");
+    }
+
+    #[test]
+    fn test_encode_reaction_custom() {
+        let emoji = ReactionType::Custom {
+            name: "ship_it".to_string(),
+            id: 42.into(),
+        };
+        assert_eq!(encode_reaction(&emoji), "ship_it:42");
+    }
+
+    #[test]
+    fn test_route_path_and_method() {
+        let route = Route::EditMessage {
+            channel_id: ChannelId(1),
+            message_id: MessageId(2),
+        };
+
+        assert_eq!(route.method(), Method::PATCH);
+        assert_eq!(route.path(), "/channels/1/messages/2");
+    }
+
+    #[test]
+    fn test_route_key_collapses_minor_ids() {
+        let route = Route::DeleteMessage {
+            channel_id: ChannelId(123),
+            message_id: MessageId(456),
+        };
+
+        assert_eq!(
+            route.key(),
+            RouteKey {
+                route: "DELETE /channels/{major}/messages/{id}".to_string(),
+                major: "123".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_reaction_routes_share_bucket_across_emoji() {
+        let thumbs = ReactionType::Unicode("👍".to_string());
+        let party = ReactionType::Unicode("🎉".to_string());
+
+        let first = Route::AddReaction {
+            channel_id: ChannelId(1),
+            message_id: MessageId(2),
+            emoji: &thumbs,
+        };
+        let second = Route::AddReaction {
+            channel_id: ChannelId(1),
+            message_id: MessageId(3),
+            emoji: &party,
+        };
+
+        assert_ne!(first.path(), second.path());
+        assert_eq!(first.key(), second.key());
+    }
+
+    #[test]
+    fn test_webhook_route_key_hashes_token() {
+        let route = Route::EditWebhookMessage {
+            webhook_id: WebhookId(1),
+            token: "secret",
+            message_id: MessageId(2),
+        };
+        let other = Route::EditWebhookMessage {
+            webhook_id: WebhookId(1),
+            token: "other-secret",
+            message_id: MessageId(2),
+        };
+
+        assert_eq!(route.path(), "/webhooks/1/secret/messages/2");
+        assert!(route.has_token());
+        assert_eq!(
+            route.key(),
+            RouteKey {
+                route: "PATCH /webhooks/{major}/{major}/messages/{id}".to_string(),
+                major: format!("1/{}", hash_token("secret")),
+            }
+        );
+        assert!(!route.key().major.contains("secret"));
+        assert_ne!(route.key(), other.key());
+    }
+
+    #[test]
+    fn test_command_route_key_has_no_major() {
+        let route = Route::DeleteGuildCommand {
+            application_id: ApplicationId(1),
+            guild_id: GuildId(2),
+            command_id: CommandId(3),
+        };
+
+        assert_eq!(route.path(), "/applications/1/guilds/2/commands/3");
+        assert_eq!(
+            route.key(),
+            RouteKey {
+                route: "DELETE /applications/{id}/guilds/{id}/commands/{id}".to_string(),
+                major: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_followup_routes_share_webhook_buckets() {
+        let route = Route::EditFollowupMessage {
+            application_id: ApplicationId(1),
+            token: "interaction-token",
+            message_id: MessageId(2),
+        };
+
+        assert_eq!(route.path(), "/webhooks/1/interaction-token/messages/2");
+        assert_eq!(
+            route.key(),
+            RouteKey {
+                route: "PATCH /webhooks/{major}/{major}/messages/{id}".to_string(),
+                major: format!("1/{}", hash_token("interaction-token")),
+            }
+        );
+    }
+
+    #[test]
+    fn test_route_resource() {
+        let route = Route::PinMessage {
+            channel_id: ChannelId(1),
+            message_id: MessageId(2),
+        };
+
+        assert_eq!(route.resource(), ("Message", "2".to_string()));
+        assert_eq!(
+            Route::LeaveThread {
+                thread_id: ChannelId(9)
+            }
+            .resource(),
+            ("Thread", "9".to_string())
+        );
+    }
+}
@@ -0,0 +1,1568 @@
+use chrono::Utc;
+use reqwest::Method;
+use serde_json::Value;
+use std::{
+    collections::VecDeque,
+    fmt,
+    sync::{Mutex, MutexGuard},
+};
+use types::{
+    channel::{Channel, ChannelId, ChannelType, ThreadList, ThreadMetadata},
+    command::{ApplicationCommand, ApplicationId, CommandId},
+    emoji::{Emoji, ReactionType},
+    guild::{Guild, GuildId},
+    interaction::InteractionId,
+    message::{Message, MessageId, Reaction},
+    user::{User, UserId},
+};
+
+use crate::{
+    bodies::{
+        CreateCommand, CreateMessage, EditWebhookMessage, InteractionCallbackData,
+        InteractionCallbackType, InteractionMessage, InteractionResponse, StartThread,
+        validate_content,
+    },
+    errors::{
+        client::ClientError,
+        discord::{DiscordError, ErrorCode},
+    },
+    files::FileUpload,
+    queries::{
+        GetArchivedThreadsQuery, GetCurrentUserGuildsQuery, GetMessagesQuery, GetReactionsQuery,
+    },
+    routes::Route,
+    traits::rest_client::{BULK_DELETE_MAX, BULK_DELETE_MIN, MAX_ATTACHMENTS, RestClient},
+};
+
+const DEFAULT_MESSAGE_LIMIT: usize = 50;
+const DEFAULT_GUILD_LIMIT: usize = 200;
+const DEFAULT_AUTO_ARCHIVE_MINUTES: u32 = 1440;
+
+/// A request made against a `MockRestClient`, as it would have been sent to
+/// Discord.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Call {
+    pub method: Method,
+    pub path: String,
+    pub body: Option<Value>,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)
+    }
+}
+
+#[derive(Debug)]
+struct ScriptedError {
+    method: Option<Method>,
+    path: Option<String>,
+    error: ClientError,
+}
+
+impl ScriptedError {
+    fn matches(&self, call: &Call) -> bool {
+        self.method.as_ref().is_none_or(|m| *m == call.method)
+            && self.path.as_ref().is_none_or(|p| *p == call.path)
+    }
+}
+
+/// A message sent in response to an interaction. These are kept apart from
+/// channel messages, as the mock does not know which channel an interaction
+/// came from.
+#[derive(Debug)]
+struct InteractionReply {
+    token: String,
+    original: bool,
+    message: Message,
+}
+
+#[derive(Debug)]
+struct MockState {
+    current_user: User,
+    guilds: Vec<Guild>,
+    channels: Vec<Channel>,
+    messages: Vec<Message>,
+    commands: Vec<ApplicationCommand>,
+    acknowledged: Vec<InteractionId>,
+    interaction_replies: Vec<InteractionReply>,
+    calls: Vec<Call>,
+    errors: VecDeque<ScriptedError>,
+    next_id: u64,
+}
+
+impl MockState {
+    fn next_id(&mut self) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        id
+    }
+
+    fn bump_next_id(&mut self, id: u64) {
+        self.next_id = self.next_id.max(id + 1);
+    }
+
+    fn channel(&self, channel_id: ChannelId) -> Option<&Channel> {
+        self.channels.iter().find(|c| c.id == channel_id)
+    }
+
+    fn message_mut(
+        &mut self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Option<&mut Message> {
+        self.messages
+            .iter_mut()
+            .find(|m| m.channel_id == channel_id && m.id == message_id)
+    }
+}
+
+/// An in-memory `RestClient` for tests.
+///
+/// It stores channels and messages, records every call and answers the way
+/// Discord would, including the same validation `HttpClient` does. Errors
+/// can be scripted with `fail_next` and `fail_route`.
+#[derive(Debug)]
+pub struct MockRestClient {
+    state: Mutex<MockState>,
+}
+
+impl Default for MockRestClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockRestClient {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(MockState {
+                current_user: User {
+                    id: UserId(1),
+                    email: String::new(),
+                    username: "mock-bot".to_string(),
+                    discriminator: "0".to_string(),
+                    global_name: None,
+                },
+                guilds: Vec::new(),
+                channels: Vec::new(),
+                messages: Vec::new(),
+                commands: Vec::new(),
+                acknowledged: Vec::new(),
+                interaction_replies: Vec::new(),
+                calls: Vec::new(),
+                errors: VecDeque::new(),
+                next_id: 1000,
+            }),
+        }
+    }
+
+    /// Sets the user that authors sent messages and owns reactions.
+    pub fn with_current_user(self, user: User) -> Self {
+        self.lock().current_user = user;
+        self
+    }
+
+    /// Adds a guild the current user is a member of.
+    pub fn add_guild(&self, guild: Guild) {
+        let mut state = self.lock();
+        state.bump_next_id(guild.id.0);
+        state.guilds.push(guild);
+        state.guilds.sort_by_key(|g| g.id.0);
+    }
+
+    pub fn add_channel(&self, channel: Channel) {
+        let mut state = self.lock();
+        state.bump_next_id(channel.id.0);
+        state.channels.push(channel);
+    }
+
+    pub fn add_message(&self, message: Message) {
+        let mut state = self.lock();
+        state.bump_next_id(message.id.0);
+        state.messages.push(message);
+        state.messages.sort_by_key(|m| m.id);
+    }
+
+    pub fn channels(&self) -> Vec<Channel> {
+        self.lock().channels.clone()
+    }
+
+    /// The messages stored for `channel_id`, oldest first.
+    pub fn messages(&self, channel_id: ChannelId) -> Vec<Message> {
+        self.lock()
+            .messages
+            .iter()
+            .filter(|m| m.channel_id == channel_id)
+            .cloned()
+            .collect()
+    }
+
+    /// The application commands registered globally (`guild_id` `None`) or
+    /// in `guild_id`.
+    pub fn application_commands(&self, guild_id: Option<GuildId>) -> Vec<ApplicationCommand> {
+        self.lock()
+            .commands
+            .iter()
+            .filter(|c| c.guild_id == guild_id)
+            .cloned()
+            .collect()
+    }
+
+    /// The original response and follow-ups sent with the interaction
+    /// `token`, in the order they were sent.
+    pub fn interaction_replies(&self, token: &str) -> Vec<Message> {
+        self.lock()
+            .interaction_replies
+            .iter()
+            .filter(|r| r.token == token)
+            .map(|r| r.message.clone())
+            .collect()
+    }
+
+    /// Every call made so far, in order.
+    pub fn calls(&self) -> Vec<Call> {
+        self.lock().calls.clone()
+    }
+
+    /// Makes the next call, whatever it is, fail with `error`.
+    pub fn fail_next(&self, error: ClientError) {
+        self.lock().errors.push_back(ScriptedError {
+            method: None,
+            path: None,
+            error,
+        });
+    }
+
+    /// Makes the next call to `method` `path` fail with `error`, e.g.
+    /// `fail_route(Method::POST, "/channels/1/messages", ClientError::Forbidden(None))`.
+    pub fn fail_route(&self, method: Method, path: impl Into<String>, error: ClientError) {
+        self.lock().errors.push_back(ScriptedError {
+            method: Some(method),
+            path: Some(path.into()),
+            error,
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Records the call and returns the state, or the first scripted error
+    /// that matches it.
+    fn call(
+        &self,
+        route: Route<'_>,
+        body: Option<Value>,
+    ) -> Result<MutexGuard<'_, MockState>, ClientError> {
+        let mut state = self.lock();
+        let call = Call {
+            method: route.method(),
+            path: route.path(),
+            body,
+        };
+
+        let scripted = state.errors.iter().position(|e| e.matches(&call));
+        state.calls.push(call);
+
+        match scripted.and_then(|i| state.errors.remove(i)) {
+            Some(scripted) => Err(scripted.error),
+            None => Ok(state),
+        }
+    }
+}
+
+/// The error `HttpClient` returns for a 404, with the envelope Discord sends
+/// for the missing resource.
+fn not_found(route: Route<'_>) -> ClientError {
+    let (resource_type, resource_id) = route.resource();
+    let (code, message) = match resource_type {
+        "Channel" | "Thread" => (ErrorCode::UnknownChannel, "Unknown Channel"),
+        "Guild" => (ErrorCode::UnknownGuild, "Unknown Guild"),
+        "Message" => (ErrorCode::UnknownMessage, "Unknown Message"),
+        "User" => (ErrorCode::UnknownUser, "Unknown User"),
+        "Webhook" => (ErrorCode::UnknownWebhook, "Unknown Webhook"),
+        "Interaction" => (ErrorCode::UnknownInteraction, "Unknown interaction"),
+        "Command" => (
+            ErrorCode::UnknownApplicationCommand,
+            "Unknown application command",
+        ),
+        _ => (ErrorCode::General, "404: Not Found"),
+    };
+
+    ClientError::NotFound {
+        resource_type: resource_type.to_string(),
+        resource_id,
+        error: Some(DiscordError {
+            status: 404,
+            code,
+            message: message.to_string(),
+            errors: Vec::new(),
+        }),
+    }
+}
+
+fn to_body<T: serde::Serialize + ?Sized>(value: &T) -> Option<Value> {
+    serde_json::to_value(value).ok()
+}
+
+fn new_command(
+    state: &mut MockState,
+    application_id: ApplicationId,
+    guild_id: Option<GuildId>,
+    command: &CreateCommand,
+) -> ApplicationCommand {
+    let id = CommandId(state.next_id());
+
+    ApplicationCommand {
+        id,
+        application_id,
+        guild_id,
+        kind: command.kind,
+        name: command.name.clone(),
+        description: command.description.clone(),
+        options: command.options.clone(),
+        default_member_permissions: command.default_member_permissions.clone(),
+        nsfw: command.nsfw,
+        version: id,
+    }
+}
+
+fn interaction_reply(
+    state: &mut MockState,
+    token: &str,
+    original: bool,
+    message: &InteractionMessage,
+) -> InteractionReply {
+    InteractionReply {
+        token: token.to_string(),
+        original,
+        message: Message {
+            id: MessageId(state.next_id()),
+            author: state.current_user.clone(),
+            content: message.content.clone().unwrap_or_default(),
+            channel_id: ChannelId(0),
+            timestamp: Utc::now(),
+            reactions: Vec::new(),
+            attachments: Vec::new(),
+            embeds: message.embeds.clone(),
+            pinned: false,
+            message_reference: None,
+            referenced_message: None,
+        },
+    }
+}
+
+fn edit_reply(message: &mut Message, edit: &EditWebhookMessage) {
+    if let Some(content) = &edit.content {
+        message.content = content.clone();
+    }
+
+    if let Some(embeds) = &edit.embeds {
+        message.embeds = embeds.clone();
+    }
+}
+
+fn to_emoji(reaction: &ReactionType) -> Emoji {
+    match reaction {
+        ReactionType::Unicode(name) => Emoji {
+            id: None,
+            name: Some(name.clone()),
+            animated: false,
+        },
+        ReactionType::Custom { name, id } => Emoji {
+            id: Some(*id),
+            name: Some(name.clone()),
+            animated: false,
+        },
+    }
+}
+
+fn reaction_mut<'m>(message: &'m mut Message, emoji: &ReactionType) -> Option<&'m mut Reaction> {
+    message
+        .reactions
+        .iter_mut()
+        .find(|r| ReactionType::from(r.emoji.clone()) == *emoji)
+}
+
+impl RestClient for MockRestClient {
+    async fn get_current_user(&self) -> Result<User, ClientError> {
+        let state = self.call(Route::GetCurrentUser, None)?;
+
+        Ok(state.current_user.clone())
+    }
+
+    async fn get_current_user_guilds(
+        &self,
+        query: GetCurrentUserGuildsQuery,
+    ) -> Result<Vec<Guild>, ClientError> {
+        let state = self.call(Route::GetCurrentUserGuilds, None)?;
+
+        let limit = query.limit.map_or(DEFAULT_GUILD_LIMIT, usize::from);
+        let in_range = |g: &&Guild| {
+            query.before.is_none_or(|before| g.id.0 < before.0)
+                && query.after.is_none_or(|after| g.id.0 > after.0)
+        };
+
+        // Like Discord, `before` returns the guilds closest to it.
+        let mut guilds: Vec<Guild> = state.guilds.iter().filter(in_range).cloned().collect();
+        if query.before.is_some() && query.after.is_none() {
+            let skip = guilds.len().saturating_sub(limit);
+            guilds.drain(..skip);
+        }
+        guilds.truncate(limit);
+
+        Ok(guilds)
+    }
+
+    async fn get_channels(&self, guild_id: GuildId) -> Result<Vec<Channel>, ClientError> {
+        let state = self.call(Route::GetGuildChannels { guild_id }, None)?;
+
+        Ok(state
+            .channels
+            .iter()
+            .filter(|c| c.guild_id == Some(guild_id) && !c.kind.is_thread())
+            .cloned()
+            .collect())
+    }
+
+    async fn get_messages(
+        &self,
+        channel_id: ChannelId,
+        query: GetMessagesQuery,
+    ) -> Result<Vec<Message>, ClientError> {
+        let route = Route::GetMessages { channel_id };
+        let state = self.call(route, None)?;
+
+        if state.channel(channel_id).is_none() {
+            return Err(not_found(route));
+        }
+
+        let limit = query.limit.map_or(DEFAULT_MESSAGE_LIMIT, usize::from);
+        let history = state.messages.iter().filter(|m| m.channel_id == channel_id);
+
+        let mut page: Vec<Message> = if let Some(after) = query.after {
+            history
+                .filter(|m| m.id > after)
+                .take(limit)
+                .cloned()
+                .collect()
+        } else if let Some(around) = query.around {
+            let history: Vec<&Message> = history.collect();
+            let center = history.partition_point(|m| m.id < around);
+            let start = center.saturating_sub(limit / 2);
+
+            history
+                .into_iter()
+                .skip(start)
+                .take(limit)
+                .cloned()
+                .collect()
+        } else {
+            let mut page: Vec<Message> = history
+                .filter(|m| query.before.is_none_or(|before| m.id < before))
+                .rev()
+                .take(limit)
+                .cloned()
+                .collect();
+            page.reverse();
+            page
+        };
+
+        page.reverse();
+        Ok(page)
+    }
+
+    async fn send_message(
+        &self,
+        channel_id: ChannelId,
+        content: &str,
+    ) -> Result<Message, ClientError> {
+        self.create_message(channel_id, &CreateMessage::with_content(content))
+            .await
+    }
+
+    async fn create_message(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+    ) -> Result<Message, ClientError> {
+        self.send_files(channel_id, message, &[]).await
+    }
+
+    async fn send_files(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+        files: &[FileUpload],
+    ) -> Result<Message, ClientError> {
+        let route = Route::CreateMessage { channel_id };
+
+        message.validate()?;
+
+        if files.len() > MAX_ATTACHMENTS {
+            return Err(ClientError::TooManyAttachments { count: files.len() });
+        }
+
+        let mut body = to_body(message);
+        if let (Some(Value::Object(object)), false) = (&mut body, files.is_empty()) {
+            let attachments = files
+                .iter()
+                .enumerate()
+                .map(|(i, file)| serde_json::json!({ "id": i, "filename": file.filename }))
+                .collect();
+            object.insert("attachments".into(), Value::Array(attachments));
+        }
+
+        let mut state = self.call(route, body)?;
+
+        if state.channel(channel_id).is_none() {
+            return Err(not_found(route));
+        }
+
+        let id = MessageId(state.next_id());
+        let referenced_message = message
+            .message_reference
+            .as_ref()
+            .and_then(|r| r.message_id)
+            .and_then(|reply_to| state.messages.iter().find(|m| m.id == reply_to))
+            .cloned()
+            .map(Box::new);
+
+        let created = Message {
+            id,
+            author: state.current_user.clone(),
+            content: message.content.clone().unwrap_or_default(),
+            channel_id,
+            timestamp: Utc::now(),
+            reactions: Vec::new(),
+            attachments: Vec::new(),
+            embeds: message.embeds.clone(),
+            pinned: false,
+            message_reference: message.message_reference.clone(),
+            referenced_message,
+        };
+
+        state.messages.push(created.clone());
+        Ok(created)
+    }
+
+    async fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: &str,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditMessage {
+            channel_id,
+            message_id,
+        };
+
+        validate_content(content)?;
+
+        let mut state = self.call(route, to_body(&serde_json::json!({ "content": content })))?;
+
+        let message = state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        message.content = content.to_string();
+        Ok(message.clone())
+    }
+
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteMessage {
+            channel_id,
+            message_id,
+        };
+        let mut state = self.call(route, None)?;
+
+        let before = state.messages.len();
+        state
+            .messages
+            .retain(|m| !(m.channel_id == channel_id && m.id == message_id));
+
+        if state.messages.len() == before {
+            return Err(not_found(route));
+        }
+
+        Ok(())
+    }
+
+    async fn bulk_delete_messages(
+        &self,
+        channel_id: ChannelId,
+        message_ids: &[MessageId],
+    ) -> Result<(), ClientError> {
+        let route = Route::BulkDeleteMessages { channel_id };
+
+        if !(BULK_DELETE_MIN..=BULK_DELETE_MAX).contains(&message_ids.len()) {
+            return Err(ClientError::InvalidBulkDeleteCount {
+                count: message_ids.len(),
+            });
+        }
+
+        let mut state = self.call(
+            route,
+            to_body(&serde_json::json!({ "messages": message_ids })),
+        )?;
+
+        state
+            .messages
+            .retain(|m| !(m.channel_id == channel_id && message_ids.contains(&m.id)));
+
+        Ok(())
+    }
+
+    async fn add_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> Result<(), ClientError> {
+        let route = Route::AddReaction {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        let mut state = self.call(route, None)?;
+
+        let message = state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        match reaction_mut(message, emoji) {
+            Some(reaction) if reaction.me => {}
+            Some(reaction) => {
+                reaction.count += 1;
+                reaction.me = true;
+            }
+            None => message.reactions.push(Reaction {
+                count: 1,
+                me: true,
+                emoji: to_emoji(emoji),
+            }),
+        }
+
+        Ok(())
+    }
+
+    async fn remove_own_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> Result<(), ClientError> {
+        let route = Route::RemoveOwnReaction {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        let mut state = self.call(route, None)?;
+
+        let message = state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        if let Some(reaction) = reaction_mut(message, emoji).filter(|r| r.me) {
+            reaction.count -= 1;
+            reaction.me = false;
+        }
+
+        message.reactions.retain(|r| r.count > 0);
+        Ok(())
+    }
+
+    async fn remove_user_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        user_id: UserId,
+    ) -> Result<(), ClientError> {
+        let route = Route::RemoveUserReaction {
+            channel_id,
+            message_id,
+            emoji,
+            user_id,
+        };
+        let mut state = self.call(route, None)?;
+        let own = state.current_user.id == user_id;
+
+        let message = state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        if let Some(reaction) = reaction_mut(message, emoji).filter(|r| r.count > 0) {
+            reaction.count -= 1;
+            if own {
+                reaction.me = false;
+            }
+        }
+
+        message.reactions.retain(|r| r.count > 0);
+        Ok(())
+    }
+
+    async fn get_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        _query: GetReactionsQuery,
+    ) -> Result<Vec<User>, ClientError> {
+        let route = Route::GetReactions {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        let mut state = self.call(route, None)?;
+        let current_user = state.current_user.clone();
+
+        let message = state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        // Only our own reactions are tracked per user.
+        Ok(match reaction_mut(message, emoji) {
+            Some(reaction) if reaction.me => vec![current_user],
+            _ => Vec::new(),
+        })
+    }
+
+    async fn get_pinned_messages(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Message>, ClientError> {
+        let state = self.call(Route::GetPins { channel_id }, None)?;
+
+        Ok(state
+            .messages
+            .iter()
+            .rev()
+            .filter(|m| m.channel_id == channel_id && m.pinned)
+            .cloned()
+            .collect())
+    }
+
+    async fn pin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::PinMessage {
+            channel_id,
+            message_id,
+        };
+        let mut state = self.call(route, None)?;
+
+        state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?
+            .pinned = true;
+
+        Ok(())
+    }
+
+    async fn unpin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::UnpinMessage {
+            channel_id,
+            message_id,
+        };
+        let mut state = self.call(route, None)?;
+
+        state
+            .message_mut(channel_id, message_id)
+            .ok_or_else(|| not_found(route))?
+            .pinned = false;
+
+        Ok(())
+    }
+
+    async fn start_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        thread: &StartThread,
+    ) -> Result<Channel, ClientError> {
+        let route = Route::StartThreadFromMessage {
+            channel_id,
+            message_id,
+        };
+        let mut state = self.call(route, to_body(thread))?;
+
+        if state.message_mut(channel_id, message_id).is_none() {
+            return Err(not_found(route));
+        }
+
+        // Threads started from a message share the message's ID.
+        let created = new_thread(
+            &state,
+            ChannelId(message_id.0),
+            channel_id,
+            thread,
+            ChannelType::PublicThread,
+        );
+
+        state.channels.push(created.clone());
+        Ok(created)
+    }
+
+    async fn start_thread(
+        &self,
+        channel_id: ChannelId,
+        thread: &StartThread,
+    ) -> Result<Channel, ClientError> {
+        let route = Route::StartThread { channel_id };
+        let kind = thread.kind.unwrap_or(ChannelType::PublicThread);
+        let mut state = self.call(
+            route,
+            to_body(&StartThread {
+                kind: Some(kind),
+                ..thread.clone()
+            }),
+        )?;
+
+        if state.channel(channel_id).is_none() {
+            return Err(not_found(route));
+        }
+
+        let id = ChannelId(state.next_id());
+        let created = new_thread(&state, id, channel_id, thread, kind);
+
+        state.channels.push(created.clone());
+        Ok(created)
+    }
+
+    async fn get_active_threads(&self, guild_id: GuildId) -> Result<ThreadList, ClientError> {
+        let state = self.call(Route::GetActiveThreads { guild_id }, None)?;
+
+        let threads = state
+            .channels
+            .iter()
+            .filter(|c| c.guild_id == Some(guild_id) && c.kind.is_thread())
+            .filter(|c| !c.thread_metadata.as_ref().is_some_and(|m| m.archived))
+            .cloned()
+            .collect();
+
+        Ok(ThreadList {
+            threads,
+            members: Vec::new(),
+            has_more: false,
+        })
+    }
+
+    async fn get_public_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> Result<ThreadList, ClientError> {
+        let state = self.call(Route::GetPublicArchivedThreads { channel_id }, None)?;
+
+        Ok(archived_threads(&state, channel_id, query, |kind| {
+            kind != ChannelType::PrivateThread
+        }))
+    }
+
+    async fn get_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> Result<ThreadList, ClientError> {
+        let state = self.call(Route::GetPrivateArchivedThreads { channel_id }, None)?;
+
+        Ok(archived_threads(&state, channel_id, query, |kind| {
+            kind == ChannelType::PrivateThread
+        }))
+    }
+
+    async fn join_thread(&self, thread_id: ChannelId) -> Result<(), ClientError> {
+        let route = Route::JoinThread { thread_id };
+        let state = self.call(route, None)?;
+
+        state.channel(thread_id).ok_or_else(|| not_found(route))?;
+        Ok(())
+    }
+
+    async fn leave_thread(&self, thread_id: ChannelId) -> Result<(), ClientError> {
+        let route = Route::LeaveThread { thread_id };
+        let state = self.call(route, None)?;
+
+        state.channel(thread_id).ok_or_else(|| not_found(route))?;
+        Ok(())
+    }
+
+    async fn get_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+    ) -> Result<Vec<ApplicationCommand>, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::GetGuildCommands {
+                application_id,
+                guild_id,
+            },
+            None => Route::GetGlobalCommands { application_id },
+        };
+        let state = self.call(route, None)?;
+
+        Ok(state
+            .commands
+            .iter()
+            .filter(|c| c.application_id == application_id && c.guild_id == guild_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn create_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command: &CreateCommand,
+    ) -> Result<ApplicationCommand, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::CreateGuildCommand {
+                application_id,
+                guild_id,
+            },
+            None => Route::CreateGlobalCommand { application_id },
+        };
+
+        command.validate()?;
+
+        let mut state = self.call(route, to_body(command))?;
+
+        // Creating a command with an existing name and type replaces it.
+        state.commands.retain(|c| {
+            !(c.application_id == application_id
+                && c.guild_id == guild_id
+                && c.name == command.name
+                && c.kind == command.kind)
+        });
+
+        let created = new_command(&mut state, application_id, guild_id, command);
+        state.commands.push(created.clone());
+        Ok(created)
+    }
+
+    async fn bulk_overwrite_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        commands: &[CreateCommand],
+    ) -> Result<Vec<ApplicationCommand>, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::BulkOverwriteGuildCommands {
+                application_id,
+                guild_id,
+            },
+            None => Route::BulkOverwriteGlobalCommands { application_id },
+        };
+
+        for command in commands {
+            command.validate()?;
+        }
+
+        let mut state = self.call(route, to_body(commands))?;
+        let in_scope =
+            |c: &ApplicationCommand| c.application_id == application_id && c.guild_id == guild_id;
+
+        let mut replaced = Vec::with_capacity(commands.len());
+        for command in commands {
+            let existing = state
+                .commands
+                .iter()
+                .find(|c| in_scope(c) && c.name == command.name && c.kind == command.kind)
+                .map(|c| c.id);
+
+            let mut created = new_command(&mut state, application_id, guild_id, command);
+            // Discord keeps the ID of a command that is overwritten in place.
+            if let Some(id) = existing {
+                created.id = id;
+            }
+            replaced.push(created);
+        }
+
+        state.commands.retain(|c| !in_scope(c));
+        state.commands.extend(replaced.iter().cloned());
+        Ok(replaced)
+    }
+
+    async fn delete_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command_id: CommandId,
+    ) -> Result<(), ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::DeleteGuildCommand {
+                application_id,
+                guild_id,
+                command_id,
+            },
+            None => Route::DeleteGlobalCommand {
+                application_id,
+                command_id,
+            },
+        };
+        let mut state = self.call(route, None)?;
+
+        let index = state
+            .commands
+            .iter()
+            .position(|c| {
+                c.id == command_id && c.application_id == application_id && c.guild_id == guild_id
+            })
+            .ok_or_else(|| not_found(route))?;
+
+        state.commands.remove(index);
+        Ok(())
+    }
+
+    async fn create_interaction_response(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        response: &InteractionResponse,
+    ) -> Result<(), ClientError> {
+        let route = Route::CreateInteractionResponse {
+            interaction_id,
+            token,
+        };
+
+        response.validate()?;
+
+        let mut state = self.call(route, to_body(response))?;
+
+        if state.acknowledged.contains(&interaction_id) {
+            return Err(ClientError::ApiError {
+                status: 400,
+                message: "Interaction has already been acknowledged.".to_string(),
+            });
+        }
+        state.acknowledged.push(interaction_id);
+
+        let message = match (response.kind, &response.data) {
+            (
+                InteractionCallbackType::ChannelMessageWithSource,
+                Some(InteractionCallbackData::Message(message)),
+            ) => message.clone(),
+            (InteractionCallbackType::DeferredChannelMessageWithSource, _) => {
+                InteractionMessage::default()
+            }
+            _ => return Ok(()),
+        };
+
+        let reply = interaction_reply(&mut state, token, true, &message);
+        state.interaction_replies.push(reply);
+        Ok(())
+    }
+
+    async fn edit_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &EditWebhookMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditOriginalResponse {
+            application_id,
+            token,
+        };
+
+        message.validate()?;
+
+        let mut state = self.call(route, to_body(message))?;
+
+        let reply = state
+            .interaction_replies
+            .iter_mut()
+            .find(|r| r.token == token && r.original)
+            .ok_or_else(|| not_found(route))?;
+
+        edit_reply(&mut reply.message, message);
+        Ok(reply.message.clone())
+    }
+
+    async fn delete_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteOriginalResponse {
+            application_id,
+            token,
+        };
+        let mut state = self.call(route, None)?;
+
+        let index = state
+            .interaction_replies
+            .iter()
+            .position(|r| r.token == token && r.original)
+            .ok_or_else(|| not_found(route))?;
+
+        state.interaction_replies.remove(index);
+        Ok(())
+    }
+
+    async fn create_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &InteractionMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::CreateFollowupMessage {
+            application_id,
+            token,
+        };
+
+        message.validate()?;
+
+        let mut state = self.call(route, to_body(message))?;
+
+        let reply = interaction_reply(&mut state, token, false, message);
+        let created = reply.message.clone();
+        state.interaction_replies.push(reply);
+        Ok(created)
+    }
+
+    async fn edit_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+        message: &EditWebhookMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditFollowupMessage {
+            application_id,
+            token,
+            message_id,
+        };
+
+        message.validate()?;
+
+        let mut state = self.call(route, to_body(message))?;
+
+        let reply = state
+            .interaction_replies
+            .iter_mut()
+            .find(|r| r.token == token && r.message.id == message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        edit_reply(&mut reply.message, message);
+        Ok(reply.message.clone())
+    }
+
+    async fn delete_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteFollowupMessage {
+            application_id,
+            token,
+            message_id,
+        };
+        let mut state = self.call(route, None)?;
+
+        let index = state
+            .interaction_replies
+            .iter()
+            .position(|r| r.token == token && r.message.id == message_id)
+            .ok_or_else(|| not_found(route))?;
+
+        state.interaction_replies.remove(index);
+        Ok(())
+    }
+}
+
+fn new_thread(
+    state: &MockState,
+    id: ChannelId,
+    parent_id: ChannelId,
+    thread: &StartThread,
+    kind: ChannelType,
+) -> Channel {
+    let now = Utc::now();
+
+    Channel {
+        id,
+        kind,
+        name: thread.name.clone(),
+        guild_id: state.channel(parent_id).and_then(|c| c.guild_id),
+        parent_id: Some(parent_id),
+        owner_id: Some(state.current_user.id),
+        thread_metadata: Some(ThreadMetadata {
+            archived: false,
+            auto_archive_duration: thread
+                .auto_archive_duration
+                .unwrap_or(DEFAULT_AUTO_ARCHIVE_MINUTES),
+            archive_timestamp: now,
+            locked: false,
+            invitable: thread.invitable,
+            create_timestamp: Some(now),
+        }),
+        message_count: Some(0),
+        member_count: Some(1),
+    }
+}
+
+fn archived_threads(
+    state: &MockState,
+    channel_id: ChannelId,
+    query: GetArchivedThreadsQuery,
+    kind: impl Fn(ChannelType) -> bool,
+) -> ThreadList {
+    let mut threads: Vec<Channel> = state
+        .channels
+        .iter()
+        .filter(|c| c.parent_id == Some(channel_id) && c.kind.is_thread() && kind(c.kind))
+        .filter(|c| {
+            c.thread_metadata.as_ref().is_some_and(|m| {
+                m.archived
+                    && query
+                        .before
+                        .is_none_or(|before| m.archive_timestamp < before)
+            })
+        })
+        .cloned()
+        .collect();
+
+    threads.sort_by_key(|c| {
+        std::cmp::Reverse(c.thread_metadata.as_ref().map(|m| m.archive_timestamp))
+    });
+
+    let limit = query.limit.map_or(usize::MAX, usize::from);
+    let has_more = threads.len() > limit;
+    threads.truncate(limit);
+
+    ThreadList {
+        threads,
+        members: Vec::new(),
+        has_more,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pagination::{HistoryBound, HistoryStart, message_history};
+    use futures_util::StreamExt;
+
+    fn channel(id: u64, guild_id: u64) -> Channel {
+        Channel {
+            id: ChannelId(id),
+            kind: ChannelType::GuildText,
+            name: format!("channel-{}", id),
+            guild_id: Some(GuildId(guild_id)),
+            parent_id: None,
+            owner_id: None,
+            thread_metadata: None,
+            message_count: None,
+            member_count: None,
+        }
+    }
+
+    fn guild(id: u64) -> Guild {
+        Guild {
+            id: GuildId(id),
+            name: format!("guild-{}", id),
+            description: None,
+            icon: None,
+            owner_id: None,
+            owner: false,
+            permissions: None,
+            features: Vec::new(),
+            approximate_member_count: None,
+            approximate_presence_count: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_and_read_messages() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        let sent = client.send_message(ChannelId(1), "hello").await.unwrap();
+        client.send_message(ChannelId(1), "world").await.unwrap();
+
+        let messages = client
+            .get_messages(ChannelId(1), GetMessagesQuery::default())
+            .await
+            .unwrap();
+
+        assert_eq!(sent.author.username, "mock-bot");
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].content, "world");
+        assert_eq!(messages[1].content, "hello");
+    }
+
+    #[tokio::test]
+    async fn test_records_calls() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        client.get_channels(GuildId(10)).await.unwrap();
+        client.send_message(ChannelId(1), "hi").await.unwrap();
+
+        let calls = client.calls();
+
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0].to_string(), "GET /guilds/10/channels");
+        assert_eq!(calls[1].method, Method::POST);
+        assert_eq!(calls[1].path, "/channels/1/messages");
+        assert_eq!(
+            calls[1].body,
+            Some(serde_json::json!({
+                "content": "hi",
+                "allowed_mentions": { "parse": ["roles", "users"], "replied_user": true }
+            }))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scripted_errors() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        client.fail_route(
+            Method::POST,
+            "/channels/1/messages",
+            ClientError::Forbidden(None),
+        );
+        client.fail_next(ClientError::Unauthorized);
+
+        let first = client.get_channels(GuildId(10)).await;
+        let second = client.send_message(ChannelId(1), "hi").await;
+        let third = client.send_message(ChannelId(1), "hi").await;
+
+        assert!(matches!(first, Err(ClientError::Unauthorized)));
+        assert!(matches!(second, Err(ClientError::Forbidden(None))));
+        assert!(third.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_message_is_not_found() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        let result = client.delete_message(ChannelId(1), MessageId(99)).await;
+
+        match result {
+            Err(ClientError::NotFound {
+                resource_type,
+                resource_id,
+                error,
+            }) => {
+                assert_eq!(resource_type, "Message");
+                assert_eq!(resource_id, "99");
+                assert_eq!(error.unwrap().code, ErrorCode::UnknownMessage);
+            }
+            other => panic!("Expected NotFound error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_validates_like_http_client() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        let result = client.send_message(ChannelId(1), &"a".repeat(2001)).await;
+
+        assert!(matches!(result, Err(ClientError::MessageTooLong)));
+        assert!(client.messages(ChannelId(1)).is_empty());
+        assert!(client.calls().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reactions_and_pins() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        let message = client.send_message(ChannelId(1), "deploy").await.unwrap();
+        let emoji = ReactionType::from("👍");
+
+        client
+            .add_reaction(ChannelId(1), message.id, &emoji)
+            .await
+            .unwrap();
+        client.pin_message(ChannelId(1), message.id).await.unwrap();
+
+        let pinned = client.get_pinned_messages(ChannelId(1)).await.unwrap();
+
+        assert_eq!(pinned.len(), 1);
+        assert_eq!(pinned[0].reactions[0].count, 1);
+        assert!(pinned[0].reactions[0].me);
+    }
+
+    #[tokio::test]
+    async fn test_threads() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        let thread = client
+            .start_thread(ChannelId(1), &StartThread::new("incident"))
+            .await
+            .unwrap();
+        let active = client.get_active_threads(GuildId(10)).await.unwrap();
+
+        assert_eq!(thread.kind, ChannelType::PublicThread);
+        assert_eq!(thread.parent_id, Some(ChannelId(1)));
+        assert_eq!(active.threads.len(), 1);
+        assert!(client.join_thread(thread.id).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_drives_message_history() {
+        let client = MockRestClient::new();
+        client.add_channel(channel(1, 10));
+
+        for i in 0..250 {
+            client
+                .send_message(ChannelId(1), &format!("message {}", i))
+                .await
+                .unwrap();
+        }
+
+        let history: Vec<Message> = message_history(
+            &client,
+            ChannelId(1),
+            HistoryStart::Latest,
+            HistoryBound::Unbounded,
+        )
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+        assert_eq!(history.len(), 250);
+        assert_eq!(history[0].content, "message 249");
+        assert_eq!(history[249].content, "message 0");
+        assert_eq!(client.calls().len(), 253);
+    }
+
+    #[tokio::test]
+    async fn test_bulk_overwrite_keeps_ids_and_drops_missing_commands() {
+        let client = MockRestClient::new();
+        let app = ApplicationId(5);
+        let guild = Some(GuildId(10));
+
+        let first = client
+            .bulk_overwrite_application_commands(
+                app,
+                guild,
+                &[
+                    CreateCommand::chat_input("ping", "Check latency"),
+                    CreateCommand::chat_input("deploy", "Deploy a service"),
+                ],
+            )
+            .await
+            .unwrap();
+        let second = client
+            .bulk_overwrite_application_commands(
+                app,
+                guild,
+                &[CreateCommand::chat_input("ping", "Check the bot's latency")],
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(second[0].id, first[0].id);
+        assert_eq!(client.application_commands(guild), second);
+        assert!(client.application_commands(None).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_deferred_response_is_filled_in_by_editing() {
+        let client = MockRestClient::new();
+        let app = ApplicationId(5);
+
+        client
+            .create_interaction_response(
+                InteractionId(1),
+                "token",
+                &InteractionResponse::deferred_message(true),
+            )
+            .await
+            .unwrap();
+        let edited = client
+            .edit_original_response(app, "token", &EditWebhookMessage::with_content("done"))
+            .await
+            .unwrap();
+        client
+            .create_followup_message(app, "token", &InteractionMessage::with_content("more"))
+            .await
+            .unwrap();
+
+        let replies = client.interaction_replies("token");
+
+        assert_eq!(edited.content, "done");
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[1].content, "more");
+    }
+
+    #[tokio::test]
+    async fn test_interaction_can_only_be_acknowledged_once() {
+        let client = MockRestClient::new();
+        let response = InteractionResponse::message(InteractionMessage::with_content("pong"));
+
+        let first = client
+            .create_interaction_response(InteractionId(1), "token", &response)
+            .await;
+        let second = client
+            .create_interaction_response(InteractionId(1), "token", &response)
+            .await;
+
+        assert!(first.is_ok());
+        assert!(matches!(
+            second,
+            Err(ClientError::ApiError { status: 400, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_current_user_guilds_paginate_by_id() {
+        let client = MockRestClient::new();
+        for id in 1..=5 {
+            client.add_guild(guild(id));
+        }
+
+        let first = client
+            .get_current_user_guilds(GetCurrentUserGuildsQuery::with_limit(2))
+            .await
+            .unwrap();
+        let next = client
+            .get_current_user_guilds(GetCurrentUserGuildsQuery {
+                after: Some(first[1].id),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let before = client
+            .get_current_user_guilds(GetCurrentUserGuildsQuery {
+                before: Some(GuildId(5)),
+                limit: Some(2),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let ids = |guilds: &[Guild]| guilds.iter().map(|g| g.id.0).collect::<Vec<_>>();
+
+        assert_eq!(ids(&first), [1, 2]);
+        assert_eq!(ids(&next), [3, 4]);
+        assert_eq!(ids(&before), [3, 4]);
+        assert_eq!(
+            client.get_current_user().await.unwrap().username,
+            "mock-bot"
+        );
+    }
+}
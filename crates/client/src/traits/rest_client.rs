@@ -1,14 +1,38 @@
-use reqwest::Method;
+use serde_json::json;
 use std::future::Future;
 use types::{
-    channel::{Channel, ChannelId},
-    guild::GuildId,
-    message::Message,
+    channel::{Channel, ChannelId, ChannelType, ThreadList},
+    command::{ApplicationCommand, ApplicationId, CommandId},
+    emoji::ReactionType,
+    guild::{Guild, GuildId},
+    interaction::InteractionId,
+    message::{Message, MessageId},
+    user::{User, UserId},
 };
 
-use crate::{client::HttpClient, errors::client::ClientError, queries::GetMessagesQuery};
+use crate::{
+    bodies::{
+        CreateCommand, CreateMessage, EditWebhookMessage, InteractionMessage, InteractionResponse,
+        StartThread, validate_content,
+    },
+    client::HttpClient,
+    errors::client::ClientError,
+    files::FileUpload,
+    queries::{
+        GetArchivedThreadsQuery, GetCurrentUserGuildsQuery, GetMessagesQuery, GetReactionsQuery,
+    },
+    request::Request,
+    routes::Route,
+};
 
 pub trait RestClient {
+    /// The user the token belongs to.
+    fn get_current_user(&self) -> impl Future<Output = Result<User, ClientError>> + Send;
+    /// The guilds the current user is in, as partial guilds.
+    fn get_current_user_guilds(
+        &self,
+        query: GetCurrentUserGuildsQuery,
+    ) -> impl Future<Output = Result<Vec<Guild>, ClientError>> + Send;
     fn get_channels(
         &self,
         guild_id: GuildId,
@@ -23,13 +47,198 @@ pub trait RestClient {
         channel_id: ChannelId,
         content: &str,
     ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn create_message(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn send_files(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+        files: &[FileUpload],
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: &str,
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn bulk_delete_messages(
+        &self,
+        channel_id: ChannelId,
+        message_ids: &[MessageId],
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn add_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn remove_own_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn remove_user_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        user_id: UserId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn get_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        query: GetReactionsQuery,
+    ) -> impl Future<Output = Result<Vec<User>, ClientError>> + Send;
+    fn get_pinned_messages(
+        &self,
+        channel_id: ChannelId,
+    ) -> impl Future<Output = Result<Vec<Message>, ClientError>> + Send;
+    fn pin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn unpin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn start_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        thread: &StartThread,
+    ) -> impl Future<Output = Result<Channel, ClientError>> + Send;
+    fn start_thread(
+        &self,
+        channel_id: ChannelId,
+        thread: &StartThread,
+    ) -> impl Future<Output = Result<Channel, ClientError>> + Send;
+    fn get_active_threads(
+        &self,
+        guild_id: GuildId,
+    ) -> impl Future<Output = Result<ThreadList, ClientError>> + Send;
+    fn get_public_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> impl Future<Output = Result<ThreadList, ClientError>> + Send;
+    fn get_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> impl Future<Output = Result<ThreadList, ClientError>> + Send;
+    fn join_thread(
+        &self,
+        thread_id: ChannelId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    fn leave_thread(
+        &self,
+        thread_id: ChannelId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+
+    /// Application command methods act on the global commands when
+    /// `guild_id` is `None`, and on that guild's commands otherwise.
+    fn get_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+    ) -> impl Future<Output = Result<Vec<ApplicationCommand>, ClientError>> + Send;
+    fn create_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command: &CreateCommand,
+    ) -> impl Future<Output = Result<ApplicationCommand, ClientError>> + Send;
+    /// Replaces every command in the scope with `commands`; commands not in
+    /// the list are deleted.
+    fn bulk_overwrite_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        commands: &[CreateCommand],
+    ) -> impl Future<Output = Result<Vec<ApplicationCommand>, ClientError>> + Send;
+    fn delete_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command_id: CommandId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+
+    /// Sends the initial response to an interaction. Every interaction must
+    /// be answered exactly once, within three seconds.
+    fn create_interaction_response(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        response: &InteractionResponse,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    /// Edits the initial response, e.g. to fill in a deferred one.
+    fn edit_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &EditWebhookMessage,
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn delete_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
+    /// Sends another message for an interaction that has been responded to.
+    /// The interaction token stays valid for 15 minutes.
+    fn create_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &InteractionMessage,
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn edit_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+        message: &EditWebhookMessage,
+    ) -> impl Future<Output = Result<Message, ClientError>> + Send;
+    fn delete_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+    ) -> impl Future<Output = Result<(), ClientError>> + Send;
 }
 
+pub(crate) const MAX_ATTACHMENTS: usize = 10;
+pub(crate) const BULK_DELETE_MIN: usize = 2;
+pub(crate) const BULK_DELETE_MAX: usize = 100;
+
 impl RestClient for HttpClient {
+    async fn get_current_user(&self) -> Result<User, ClientError> {
+        self.send(Request::new(Route::GetCurrentUser)).await
+    }
+
+    async fn get_current_user_guilds(
+        &self,
+        query: GetCurrentUserGuildsQuery,
+    ) -> Result<Vec<Guild>, ClientError> {
+        let route = Route::GetCurrentUserGuilds;
+        self.send(Request::new(route).query(&query)).await
+    }
+
     async fn get_channels(&self, guild_id: GuildId) -> Result<Vec<Channel>, ClientError> {
-        let endpoint = format!("/guilds/{}/channels", guild_id);
-        self.request(Method::GET, &endpoint, None::<()>, None::<()>)
-            .await
+        let route = Route::GetGuildChannels { guild_id };
+        self.send(Request::new(route)).await
     }
 
     async fn get_messages(
@@ -37,9 +246,8 @@ impl RestClient for HttpClient {
         channel_id: ChannelId,
         query: GetMessagesQuery,
     ) -> Result<Vec<Message>, ClientError> {
-        let endpoint = format!("/channels/{}/messages", channel_id);
-        self.request(Method::GET, &endpoint, None::<()>, Some(query))
-            .await
+        let route = Route::GetMessages { channel_id };
+        self.send(Request::new(route).query(&query)).await
     }
 
     async fn send_message(
@@ -47,34 +255,414 @@ impl RestClient for HttpClient {
         channel_id: ChannelId,
         content: &str,
     ) -> Result<Message, ClientError> {
-        let endpoint = format!("/channels/{}/messages", channel_id);
+        self.create_message(channel_id, &CreateMessage::with_content(content))
+            .await
+    }
+
+    async fn create_message(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::CreateMessage { channel_id };
+
+        message.validate()?;
+
+        self.send(Request::new(route).json(message)).await
+    }
+
+    async fn send_files(
+        &self,
+        channel_id: ChannelId,
+        message: &CreateMessage,
+        files: &[FileUpload],
+    ) -> Result<Message, ClientError> {
+        let route = Route::CreateMessage { channel_id };
 
-        #[derive(serde::Serialize, Clone, Copy)]
-        struct SendMessageBody<'a> {
-            content: &'a str,
+        message.validate()?;
+
+        if files.len() > MAX_ATTACHMENTS {
+            return Err(ClientError::TooManyAttachments { count: files.len() });
         }
 
-        if content.len() > 2000 {
-            return Err(ClientError::MessageTooLong);
+        self.send(Request::new(route).multipart(message, files))
+            .await
+    }
+
+    async fn edit_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        content: &str,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditMessage {
+            channel_id,
+            message_id,
+        };
+
+        validate_content(content)?;
+
+        self.send(Request::new(route).json(&json!({ "content": content })))
+            .await
+    }
+
+    async fn delete_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteMessage {
+            channel_id,
+            message_id,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn bulk_delete_messages(
+        &self,
+        channel_id: ChannelId,
+        message_ids: &[MessageId],
+    ) -> Result<(), ClientError> {
+        let route = Route::BulkDeleteMessages { channel_id };
+
+        if !(BULK_DELETE_MIN..=BULK_DELETE_MAX).contains(&message_ids.len()) {
+            return Err(ClientError::InvalidBulkDeleteCount {
+                count: message_ids.len(),
+            });
         }
 
-        self.request(
-            Method::POST,
-            &endpoint,
-            Some(SendMessageBody { content }),
-            None::<()>,
-        )
-        .await
+        self.send(Request::new(route).json(&json!({ "messages": message_ids })))
+            .await
+    }
+
+    async fn add_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> Result<(), ClientError> {
+        let route = Route::AddReaction {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn remove_own_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+    ) -> Result<(), ClientError> {
+        let route = Route::RemoveOwnReaction {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn remove_user_reaction(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        user_id: UserId,
+    ) -> Result<(), ClientError> {
+        let route = Route::RemoveUserReaction {
+            channel_id,
+            message_id,
+            emoji,
+            user_id,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn get_reactions(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        emoji: &ReactionType,
+        query: GetReactionsQuery,
+    ) -> Result<Vec<User>, ClientError> {
+        let route = Route::GetReactions {
+            channel_id,
+            message_id,
+            emoji,
+        };
+        self.send(Request::new(route).query(&query)).await
+    }
+
+    async fn get_pinned_messages(
+        &self,
+        channel_id: ChannelId,
+    ) -> Result<Vec<Message>, ClientError> {
+        let route = Route::GetPins { channel_id };
+        self.send(Request::new(route)).await
+    }
+
+    async fn pin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::PinMessage {
+            channel_id,
+            message_id,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn unpin_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::UnpinMessage {
+            channel_id,
+            message_id,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn start_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        thread: &StartThread,
+    ) -> Result<Channel, ClientError> {
+        let route = Route::StartThreadFromMessage {
+            channel_id,
+            message_id,
+        };
+        self.send(Request::new(route).json(thread)).await
+    }
+
+    async fn start_thread(
+        &self,
+        channel_id: ChannelId,
+        thread: &StartThread,
+    ) -> Result<Channel, ClientError> {
+        let route = Route::StartThread { channel_id };
+
+        let thread = StartThread {
+            kind: Some(thread.kind.unwrap_or(ChannelType::PublicThread)),
+            ..thread.clone()
+        };
+
+        self.send(Request::new(route).json(&thread)).await
+    }
+
+    async fn get_active_threads(&self, guild_id: GuildId) -> Result<ThreadList, ClientError> {
+        let route = Route::GetActiveThreads { guild_id };
+        self.send(Request::new(route)).await
+    }
+
+    async fn get_public_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> Result<ThreadList, ClientError> {
+        let route = Route::GetPublicArchivedThreads { channel_id };
+        self.send(Request::new(route).query(&query)).await
+    }
+
+    async fn get_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+        query: GetArchivedThreadsQuery,
+    ) -> Result<ThreadList, ClientError> {
+        let route = Route::GetPrivateArchivedThreads { channel_id };
+        self.send(Request::new(route).query(&query)).await
+    }
+
+    async fn join_thread(&self, thread_id: ChannelId) -> Result<(), ClientError> {
+        let route = Route::JoinThread { thread_id };
+        self.send(Request::new(route)).await
+    }
+
+    async fn leave_thread(&self, thread_id: ChannelId) -> Result<(), ClientError> {
+        let route = Route::LeaveThread { thread_id };
+        self.send(Request::new(route)).await
+    }
+
+    async fn get_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+    ) -> Result<Vec<ApplicationCommand>, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::GetGuildCommands {
+                application_id,
+                guild_id,
+            },
+            None => Route::GetGlobalCommands { application_id },
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn create_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command: &CreateCommand,
+    ) -> Result<ApplicationCommand, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::CreateGuildCommand {
+                application_id,
+                guild_id,
+            },
+            None => Route::CreateGlobalCommand { application_id },
+        };
+
+        command.validate()?;
+
+        self.send(Request::new(route).json(command)).await
+    }
+
+    async fn bulk_overwrite_application_commands(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        commands: &[CreateCommand],
+    ) -> Result<Vec<ApplicationCommand>, ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::BulkOverwriteGuildCommands {
+                application_id,
+                guild_id,
+            },
+            None => Route::BulkOverwriteGlobalCommands { application_id },
+        };
+
+        for command in commands {
+            command.validate()?;
+        }
+
+        self.send(Request::new(route).json(&commands)).await
+    }
+
+    async fn delete_application_command(
+        &self,
+        application_id: ApplicationId,
+        guild_id: Option<GuildId>,
+        command_id: CommandId,
+    ) -> Result<(), ClientError> {
+        let route = match guild_id {
+            Some(guild_id) => Route::DeleteGuildCommand {
+                application_id,
+                guild_id,
+                command_id,
+            },
+            None => Route::DeleteGlobalCommand {
+                application_id,
+                command_id,
+            },
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn create_interaction_response(
+        &self,
+        interaction_id: InteractionId,
+        token: &str,
+        response: &InteractionResponse,
+    ) -> Result<(), ClientError> {
+        let route = Route::CreateInteractionResponse {
+            interaction_id,
+            token,
+        };
+
+        response.validate()?;
+
+        self.send(Request::new(route).json(response)).await
+    }
+
+    async fn edit_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &EditWebhookMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditOriginalResponse {
+            application_id,
+            token,
+        };
+
+        message.validate()?;
+
+        self.send(Request::new(route).json(message)).await
+    }
+
+    async fn delete_original_response(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteOriginalResponse {
+            application_id,
+            token,
+        };
+        self.send(Request::new(route)).await
+    }
+
+    async fn create_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message: &InteractionMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::CreateFollowupMessage {
+            application_id,
+            token,
+        };
+
+        message.validate()?;
+
+        self.send(Request::new(route).json(message)).await
+    }
+
+    async fn edit_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+        message: &EditWebhookMessage,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditFollowupMessage {
+            application_id,
+            token,
+            message_id,
+        };
+
+        message.validate()?;
+
+        self.send(Request::new(route).json(message)).await
+    }
+
+    async fn delete_followup_message(
+        &self,
+        application_id: ApplicationId,
+        token: &str,
+        message_id: MessageId,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteFollowupMessage {
+            application_id,
+            token,
+            message_id,
+        };
+        self.send(Request::new(route)).await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::auth::Token;
 
     #[tokio::test]
     async fn test_client_unauthorized() {
-        let client = HttpClient::new("invalid-token".to_string());
+        let client = HttpClient::new(Token::bot("invalid-token")).unwrap();
         let result = client.get_channels(GuildId(123)).await;
 
         match result {
@@ -0,0 +1,203 @@
+use reqwest::Url;
+use std::fmt;
+use types::{
+    channel::ChannelId,
+    message::{Message, MessageId},
+    webhook::WebhookId,
+};
+
+use crate::{
+    bodies::{EditWebhookMessage, ExecuteWebhook},
+    client::{HttpClient, HttpClientBuilder},
+    errors::client::ClientError,
+    queries::WebhookQuery,
+    request::Request,
+    routes::Route,
+};
+
+/// Sends, edits and deletes messages through a webhook. The webhook's URL is
+/// the only credential needed, so no bot token is involved.
+///
+/// Requests go through an `HttpClient` and share its rate-limit handling and
+/// retries. The webhook token is redacted from `Debug` output and network
+/// errors, and only a hash of it is kept in the rate-limit table.
+#[derive(Clone)]
+pub struct WebhookClient {
+    http: HttpClient,
+    id: WebhookId,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Creates a client for a URL of the form
+    /// `https://discord.com/api/webhooks/{id}/{token}`.
+    pub fn new(url: &str) -> Result<Self, ClientError> {
+        Self::with_http(HttpClientBuilder::unauthenticated().build()?, url)
+    }
+
+    /// Creates a client that sends its requests through `http`, e.g. one
+    /// configured with a proxy. `http` should not carry a bot token.
+    pub fn with_http(http: HttpClient, url: &str) -> Result<Self, ClientError> {
+        let (id, token) = parse_webhook_url(url)?;
+
+        Ok(Self { http, id, token })
+    }
+
+    pub fn id(&self) -> WebhookId {
+        self.id
+    }
+
+    pub fn http(&self) -> &HttpClient {
+        &self.http
+    }
+
+    pub fn set_base_url(&mut self, url: String) {
+        self.http.set_base_url(url);
+    }
+
+    /// Sends a message and returns it. With `thread_id`, the message is
+    /// posted in that thread of the webhook's channel.
+    pub async fn execute(
+        &self,
+        message: &ExecuteWebhook,
+        thread_id: Option<ChannelId>,
+    ) -> Result<Message, ClientError> {
+        let route = Route::ExecuteWebhook {
+            webhook_id: self.id,
+            token: &self.token,
+        };
+
+        message.validate()?;
+
+        let query = WebhookQuery {
+            wait: Some(true),
+            thread_id,
+        };
+
+        self.http
+            .send(Request::new(route).json(message).query(&query))
+            .await
+    }
+
+    pub async fn edit_message(
+        &self,
+        message_id: MessageId,
+        message: &EditWebhookMessage,
+        thread_id: Option<ChannelId>,
+    ) -> Result<Message, ClientError> {
+        let route = Route::EditWebhookMessage {
+            webhook_id: self.id,
+            token: &self.token,
+            message_id,
+        };
+
+        message.validate()?;
+
+        let query = WebhookQuery {
+            thread_id,
+            ..Default::default()
+        };
+
+        self.http
+            .send(Request::new(route).json(message).query(&query))
+            .await
+    }
+
+    pub async fn delete_message(
+        &self,
+        message_id: MessageId,
+        thread_id: Option<ChannelId>,
+    ) -> Result<(), ClientError> {
+        let route = Route::DeleteWebhookMessage {
+            webhook_id: self.id,
+            token: &self.token,
+            message_id,
+        };
+
+        let query = WebhookQuery {
+            thread_id,
+            ..Default::default()
+        };
+
+        self.http.send(Request::new(route).query(&query)).await
+    }
+}
+
+impl fmt::Debug for WebhookClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookClient")
+            .field("http", &self.http)
+            .field("id", &self.id)
+            .field("token", &"[redacted]")
+            .finish()
+    }
+}
+
+/// Extracts the ID and token from a webhook URL, ignoring the host and any
+/// API version segment so that `discordapp.com` and `ptb`/`canary` URLs work.
+fn parse_webhook_url(url: &str) -> Result<(WebhookId, String), ClientError> {
+    let invalid = |reason: &str| ClientError::InvalidWebhookUrl(reason.to_string());
+
+    let url = Url::parse(url).map_err(|_| invalid("not a valid URL"))?;
+    let mut segments = url
+        .path_segments()
+        .ok_or_else(|| invalid("missing path"))?
+        .skip_while(|segment| *segment != "webhooks")
+        .skip(1);
+
+    let id = segments
+        .next()
+        .and_then(|id| id.parse::<u64>().ok())
+        .ok_or_else(|| invalid("expected /webhooks/{id}/{token}"))?;
+
+    let token = segments
+        .next()
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| invalid("missing webhook token"))?;
+
+    Ok((WebhookId(id), token.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_webhook_url() {
+        let (id, token) =
+            parse_webhook_url("https://discord.com/api/webhooks/123456/abc-DEF_789").unwrap();
+
+        assert_eq!(id, WebhookId(123456));
+        assert_eq!(token, "abc-DEF_789");
+    }
+
+    #[test]
+    fn test_parse_webhook_url_with_version_and_legacy_host() {
+        let (id, token) =
+            parse_webhook_url("https://canary.discordapp.com/api/v10/webhooks/1/token/").unwrap();
+
+        assert_eq!(id, WebhookId(1));
+        assert_eq!(token, "token");
+    }
+
+    #[test]
+    fn test_parse_webhook_url_rejects_missing_token() {
+        let result = parse_webhook_url("https://discord.com/api/webhooks/123456");
+
+        assert!(matches!(result, Err(ClientError::InvalidWebhookUrl(_))));
+    }
+
+    #[test]
+    fn test_parse_webhook_url_rejects_non_numeric_id() {
+        let result = parse_webhook_url("https://discord.com/api/webhooks/abc/token");
+
+        assert!(matches!(result, Err(ClientError::InvalidWebhookUrl(_))));
+    }
+
+    #[test]
+    fn test_debug_redacts_token() {
+        let client = WebhookClient::new("https://discord.com/api/webhooks/1/super-secret").unwrap();
+
+        assert!(!format!("{:?}", client).contains("super-secret"));
+    }
+}
@@ -0,0 +1,158 @@
+use client::auth::Token;
+use client::bodies::CreateCommand;
+use client::client::HttpClient;
+use client::errors::client::ClientError;
+use client::traits::rest_client::RestClient;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::command::{
+    ApplicationId, CommandId, CommandOption, CommandOptionType, CommandOptionValue,
+};
+use types::guild::GuildId;
+
+fn command_json(id: u64, name: &str, guild_id: Option<u64>) -> serde_json::Value {
+    json!({
+        "id": id,
+        "application_id": 5,
+        "guild_id": guild_id,
+        "version": id,
+        "type": 1,
+        "name": name,
+        "description": "A command",
+        "default_member_permissions": null
+    })
+}
+
+#[tokio::test]
+async fn test_create_global_command() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/applications/5/commands")
+        .match_body(Matcher::Json(json!({
+            "name": "deploy",
+            "description": "A command",
+            "type": 1,
+            "options": [{
+                "type": 3,
+                "name": "env",
+                "description": "Target environment",
+                "required": true,
+                "choices": [{ "name": "Production", "value": "prod" }]
+            }]
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(command_json(100, "deploy", None).to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let command = CreateCommand::chat_input("deploy", "A command").option(
+        CommandOption::new(CommandOptionType::String, "env", "Target environment")
+            .required()
+            .choice("Production", CommandOptionValue::String("prod".to_string())),
+    );
+
+    let created = client
+        .create_application_command(ApplicationId(5), None, &command)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, CommandId(100));
+    assert_eq!(created.guild_id, None);
+}
+
+#[tokio::test]
+async fn test_bulk_overwrite_guild_commands() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("PUT", "/applications/5/guilds/10/commands")
+        .match_body(Matcher::Json(json!([
+            { "name": "ping", "description": "A command", "type": 1 },
+            { "name": "deploy", "description": "A command", "type": 1 }
+        ])))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                command_json(100, "ping", Some(10)),
+                command_json(101, "deploy", Some(10))
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let commands = client
+        .bulk_overwrite_application_commands(
+            ApplicationId(5),
+            Some(GuildId(10)),
+            &[
+                CreateCommand::chat_input("ping", "A command"),
+                CreateCommand::chat_input("deploy", "A command"),
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(commands.len(), 2);
+    assert_eq!(commands[1].guild_id, Some(GuildId(10)));
+}
+
+#[tokio::test]
+async fn test_list_and_delete_commands() {
+    let mut server = Server::new_async().await;
+
+    let _list = server
+        .mock("GET", "/applications/5/commands")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([command_json(100, "ping", None)]).to_string())
+        .create_async()
+        .await;
+
+    let _delete = server
+        .mock("DELETE", "/applications/5/guilds/10/commands/100")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let commands = client
+        .get_application_commands(ApplicationId(5), None)
+        .await
+        .unwrap();
+    let deleted = client
+        .delete_application_command(ApplicationId(5), Some(GuildId(10)), CommandId(100))
+        .await;
+
+    assert_eq!(commands[0].to_string(), "/ping");
+    assert!(deleted.is_ok());
+}
+
+#[tokio::test]
+async fn test_invalid_command_is_rejected_before_sending() {
+    let server = Server::new_async().await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let result = client
+        .bulk_overwrite_application_commands(
+            ApplicationId(5),
+            None,
+            &[CreateCommand::chat_input("ping", "")],
+        )
+        .await;
+
+    assert!(matches!(result, Err(ClientError::InvalidCommand(_))));
+}
@@ -0,0 +1,199 @@
+use client::auth::Token;
+use client::client::HttpClient;
+use client::errors::client::ClientError;
+use client::errors::discord::ErrorCode;
+use client::traits::rest_client::RestClient;
+use mockito::Server;
+use serde_json::json;
+use types::channel::ChannelId;
+use types::guild::GuildId;
+use types::message::MessageId;
+
+async fn client_for(server: &Server) -> HttpClient {
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+    client
+}
+
+#[tokio::test]
+async fn test_unknown_channel_error_code() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/123/messages")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Unknown Channel", "code": 10003 }).to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.send_message(ChannelId(123), "hello").await;
+
+    match result {
+        Err(ClientError::NotFound {
+            resource_type,
+            resource_id,
+            error: Some(error),
+        }) => {
+            assert_eq!(resource_type, "Channel");
+            assert_eq!(resource_id, "123");
+            assert_eq!(error.code, ErrorCode::UnknownChannel);
+            assert_eq!(error.status, 404);
+        }
+        other => panic!("Expected NotFound error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_missing_access_error_code() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/guilds/1/channels")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Missing Access", "code": 50001 }).to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.get_channels(GuildId(1)).await;
+
+    assert_eq!(result.unwrap_err().code(), Some(ErrorCode::MissingAccess));
+}
+
+#[tokio::test]
+async fn test_cannot_send_to_user_error_code() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/55/messages")
+        .with_status(403)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({ "message": "Cannot send messages to this user", "code": 50007 }).to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.send_message(ChannelId(55), "hi").await;
+
+    assert_eq!(
+        result.unwrap_err().code(),
+        Some(ErrorCode::CannotSendToUser)
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_form_body_field_errors() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/123/messages")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "code": 50035,
+                "message": "Invalid Form Body",
+                "errors": {
+                    "embeds": { "0": { "title": { "_errors": [{
+                        "code": "BASE_TYPE_MAX_LENGTH",
+                        "message": "Must be 256 or fewer in length."
+                    }] } } }
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.send_message(ChannelId(123), "hello").await;
+
+    match result {
+        Err(ClientError::Discord(error)) => {
+            assert_eq!(error.code, ErrorCode::InvalidFormBody);
+            assert_eq!(error.errors.len(), 1);
+            assert_eq!(error.errors[0].path, "embeds.0.title");
+            assert_eq!(error.errors[0].code, "BASE_TYPE_MAX_LENGTH");
+        }
+        other => panic!("Expected Discord error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_forbidden_without_envelope() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/guilds/1/channels")
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.get_channels(GuildId(1)).await;
+
+    match result {
+        Err(ClientError::Forbidden(None)) => (),
+        other => panic!("Expected Forbidden error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_not_found_reports_route_resource() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("DELETE", "/channels/123/messages/456")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.delete_message(ChannelId(123), MessageId(456)).await;
+
+    match result {
+        Err(ClientError::NotFound {
+            resource_type,
+            resource_id,
+            error: None,
+        }) => {
+            assert_eq!(resource_type, "Message");
+            assert_eq!(resource_id, "456");
+        }
+        other => panic!("Expected NotFound error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_not_found_with_envelope_reports_route_resource() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("DELETE", "/channels/123/messages/456")
+        .with_status(404)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "message": "Unknown Message", "code": 10008 }).to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server).await;
+    let result = client.delete_message(ChannelId(123), MessageId(456)).await;
+
+    match result {
+        Err(ClientError::NotFound {
+            resource_type,
+            resource_id,
+            error: Some(error),
+        }) => {
+            assert_eq!(resource_type, "Message");
+            assert_eq!(resource_id, "456");
+            assert_eq!(error.code, ErrorCode::UnknownMessage);
+        }
+        other => panic!("Expected NotFound error, got {:?}", other),
+    }
+}
@@ -1,3 +1,5 @@
+use client::auth::Token;
+use client::errors::gateway::GatewayError;
 use client::gateway::{Event, Gateway};
 use futures_util::{SinkExt, StreamExt};
 use serde_json::json;
@@ -5,6 +7,7 @@ use std::time::Duration;
 use tokio::net::TcpListener;
 use tokio_tungstenite::accept_async;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
+use types::command::CommandOptionValue;
 
 #[tokio::test]
 async fn test_gateway_connect_and_ready() {
@@ -63,7 +66,7 @@ async fn test_gateway_connect_and_ready() {
             .unwrap();
     });
 
-    let mut gateway = Gateway::connect_with_url("test-token".to_string(), &url)
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
         .await
         .expect("Failed to connect");
 
@@ -85,3 +88,843 @@ async fn test_gateway_connect_and_ready() {
 
     server_handle.await.unwrap();
 }
+
+#[tokio::test]
+async fn test_gateway_rejects_bearer_token() {
+    let result = Gateway::connect_with_url(Token::bearer("test-token"), "ws://127.0.0.1:1").await;
+
+    assert!(matches!(result, Err(GatewayError::UnsupportedToken)));
+}
+
+#[tokio::test]
+async fn test_gateway_interaction_create() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let url = format!("ws://{}", addr);
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws_server = accept_async(stream).await.unwrap();
+
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 45000 } });
+        ws_server
+            .send(WsMessage::Text(hello.to_string().into()))
+            .await
+            .unwrap();
+
+        ws_server.next().await.unwrap().unwrap();
+
+        let interaction = json!({
+            "op": 0,
+            "t": "INTERACTION_CREATE",
+            "s": 2,
+            "d": {
+                "id": 900,
+                "application_id": 5,
+                "type": 2,
+                "token": "interaction-token",
+                "guild_id": 10,
+                "channel_id": 20,
+                "member": {
+                    "user": { "id": 42, "username": "alice", "discriminator": "0" },
+                    "nick": null,
+                    "roles": [7],
+                    "permissions": "2147483647"
+                },
+                "data": {
+                    "id": 100,
+                    "name": "deploy",
+                    "type": 1,
+                    "options": [
+                        { "name": "env", "type": 3, "value": "prod" },
+                        { "name": "force", "type": 5, "value": true }
+                    ]
+                }
+            }
+        });
+        ws_server
+            .send(WsMessage::Text(interaction.to_string().into()))
+            .await
+            .unwrap();
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .expect("Failed to connect");
+
+    let event = gateway.next_event().await.unwrap();
+    let Event::InteractionCreate(interaction) = event else {
+        panic!("Expected INTERACTION_CREATE event, got {:?}", event);
+    };
+    let data = interaction.command_data().expect("command data");
+
+    assert_eq!(interaction.token, "interaction-token");
+    assert_eq!(interaction.author().unwrap().username, "alice");
+    assert_eq!(data.name, "deploy");
+    assert_eq!(
+        data.option("env"),
+        Some(&CommandOptionValue::String("prod".to_string()))
+    );
+    assert_eq!(
+        data.option("force"),
+        Some(&CommandOptionValue::Boolean(true))
+    );
+
+    server_handle.await.unwrap();
+}
+
+async fn send_json(
+    ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    value: serde_json::Value,
+) {
+    ws.send(WsMessage::Text(value.to_string().into()))
+        .await
+        .unwrap();
+}
+
+async fn next_json(
+    ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+) -> serde_json::Value {
+    loop {
+        let msg = ws.next().await.unwrap().unwrap();
+        let value: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+
+        // Skip heartbeats; the tests only care about identify and resume.
+        if value["op"] != 1 {
+            return value;
+        }
+    }
+}
+
+fn ready(resume_gateway_url: &str) -> serde_json::Value {
+    json!({
+        "op": 0,
+        "t": "READY",
+        "s": 1,
+        "d": {
+            "user": { "id": 1, "username": "testuser", "discriminator": "0" },
+            "guilds": [],
+            "session_id": "test-session",
+            "resume_gateway_url": resume_gateway_url
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_gateway_resumes_after_disconnect() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 45000 } });
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut first = accept_async(stream).await.unwrap();
+        send_json(&mut first, hello.clone()).await;
+        assert_eq!(next_json(&mut first).await["op"], 2);
+        send_json(&mut first, ready(&resume_url)).await;
+        send_json(
+            &mut first,
+            json!({ "op": 0, "t": "TYPING_START", "s": 2, "d": {} }),
+        )
+        .await;
+        first.close(None).await.unwrap();
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut second = accept_async(stream).await.unwrap();
+        send_json(&mut second, hello).await;
+
+        let resume = next_json(&mut second).await;
+        assert_eq!(resume["op"], 6);
+        assert_eq!(resume["d"]["token"], "test-token");
+        assert_eq!(resume["d"]["session_id"], "test-session");
+        assert_eq!(resume["d"]["seq"], 2);
+
+        send_json(
+            &mut second,
+            json!({ "op": 0, "t": "RESUMED", "s": 3, "d": {} }),
+        )
+        .await;
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Ready { .. }
+    ));
+    assert!(matches!(
+        gateway.next_event().await,
+        Err(GatewayError::UnexpectedClose)
+    ));
+    assert!(gateway.can_resume().await);
+
+    gateway.reconnect().await.unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Resumed
+    ));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_identifies_again_after_invalid_session() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        assert_eq!(next_json(&mut ws).await["op"], 2);
+
+        send_json(&mut ws, json!({ "op": 9, "d": false })).await;
+
+        let identify = next_json(&mut ws).await;
+        assert_eq!(identify["op"], 2);
+        assert_eq!(identify["d"]["token"], "test-token");
+
+        send_json(&mut ws, ready(&resume_url)).await;
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Ready { .. }
+    ));
+    assert_eq!(gateway.session_id.as_deref(), Some("test-session"));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_close_4004_is_authentication_failure() {
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        next_json(&mut ws).await;
+
+        ws.close(Some(CloseFrame {
+            code: CloseCode::from(4004),
+            reason: "Authentication failed.".into(),
+        }))
+        .await
+        .unwrap();
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("bad-token"), &url)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await,
+        Err(GatewayError::AuthenticationFailed)
+    ));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_supervisor_reconnects_and_resumes() {
+    use client::gateway::{ConnectionState, GatewaySupervisor};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 45000 } });
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut first = accept_async(stream).await.unwrap();
+        send_json(&mut first, hello.clone()).await;
+        assert_eq!(next_json(&mut first).await["op"], 2);
+        send_json(&mut first, ready(&resume_url)).await;
+        first.close(None).await.unwrap();
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut second = accept_async(stream).await.unwrap();
+        send_json(&mut second, hello).await;
+        assert_eq!(next_json(&mut second).await["op"], 6);
+        send_json(
+            &mut second,
+            json!({ "op": 0, "t": "RESUMED", "s": 2, "d": {} }),
+        )
+        .await;
+    });
+
+    let mut supervisor = GatewaySupervisor::with_url(Token::bot("test-token"), &url);
+    supervisor.set_backoff(Duration::from_millis(10), Duration::from_millis(10));
+
+    let mut next = async || supervisor.next_event().await.unwrap();
+
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connecting)
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connected)
+    ));
+    assert!(matches!(next().await, Event::Ready { .. }));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Disconnected { .. })
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Resuming)
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connected)
+    ));
+    assert!(matches!(next().await, Event::Resumed));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_supervisor_stops_on_authentication_failure() {
+    use client::gateway::GatewaySupervisor;
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        next_json(&mut ws).await;
+
+        ws.close(Some(CloseFrame {
+            code: CloseCode::from(4004),
+            reason: "Authentication failed.".into(),
+        }))
+        .await
+        .unwrap();
+    });
+
+    let mut supervisor = GatewaySupervisor::with_url(Token::bot("bad-token"), &url);
+
+    supervisor.next_event().await.unwrap();
+    supervisor.next_event().await.unwrap();
+
+    assert!(matches!(
+        supervisor.next_event().await,
+        Err(GatewayError::AuthenticationFailed)
+    ));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_measures_heartbeat_latency() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 50 } }),
+        )
+        .await;
+
+        // The first heartbeat may arrive before or after identify.
+        loop {
+            let msg = ws.next().await.unwrap().unwrap();
+            let payload: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+
+            if payload["op"] == 1 {
+                break;
+            }
+        }
+
+        send_json(&mut ws, json!({ "op": 11 })).await;
+        send_json(&mut ws, ready(&resume_url)).await;
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    assert_eq!(gateway.latency(), None);
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Ready { .. }
+    ));
+    assert!(gateway.latency().is_some());
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_detects_missing_heartbeat_ack() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 50 } }),
+        )
+        .await;
+
+        // Read everything, acknowledge nothing.
+        while let Some(Ok(_)) = ws.next().await {}
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    let result = tokio::time::timeout(Duration::from_secs(2), gateway.next_event())
+        .await
+        .expect("zombie connection was not detected");
+
+    assert!(matches!(result, Err(GatewayError::HeartbeatTimeout)));
+
+    drop(gateway);
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_reconnect_closes_old_connection() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 45000 } });
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut first = accept_async(stream).await.unwrap();
+        send_json(&mut first, hello.clone()).await;
+        assert_eq!(next_json(&mut first).await["op"], 2);
+        send_json(&mut first, ready(&resume_url)).await;
+        send_json(&mut first, json!({ "op": 7, "d": null })).await;
+
+        let msg = loop {
+            match first.next().await.unwrap().unwrap() {
+                WsMessage::Text(_) => continue,
+                other => break other,
+            }
+        };
+        let WsMessage::Close(Some(frame)) = msg else {
+            panic!("Expected a close frame, got {:?}", msg);
+        };
+        assert_eq!(u16::from(frame.code), 4000);
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut second = accept_async(stream).await.unwrap();
+        send_json(&mut second, hello).await;
+        assert_eq!(next_json(&mut second).await["op"], 6);
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Ready { .. }
+    ));
+    assert!(matches!(
+        gateway.next_event().await,
+        Err(GatewayError::ReconnectRequired)
+    ));
+
+    tokio::time::timeout(Duration::from_secs(2), gateway.reconnect())
+        .await
+        .expect("the old connection was never closed")
+        .unwrap();
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_close_sends_close_frame() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        assert_eq!(next_json(&mut ws).await["op"], 2);
+
+        let msg = ws.next().await.unwrap().unwrap();
+        let WsMessage::Close(Some(frame)) = msg else {
+            panic!("Expected a close frame, got {:?}", msg);
+        };
+
+        assert_eq!(u16::from(frame.code), 1000);
+    });
+
+    let gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    gateway.close(1000).await.unwrap();
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_drop_stops_heartbeat() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+
+        // The heartbeat task shares the socket, so it only closes once the
+        // task is gone too.
+        while let Some(Ok(_)) = ws.next().await {}
+    });
+
+    let gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    drop(gateway);
+
+    tokio::time::timeout(Duration::from_secs(2), server_handle)
+        .await
+        .expect("the heartbeat task outlived the gateway")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_identifies_with_configured_intents() {
+    use client::gateway::{GatewayOptions, Intents};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let intents = Intents::GUILDS | Intents::GUILD_MESSAGES | Intents::MESSAGE_CONTENT;
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+
+        let identify = next_json(&mut ws).await;
+        assert_eq!(identify["op"], 2);
+        assert_eq!(identify["d"]["intents"], intents.bits());
+    });
+
+    let options = GatewayOptions::default()
+        .with_url(&url)
+        .with_intents(intents);
+    let gateway = Gateway::connect_with_options(Token::bot("test-token"), options)
+        .await
+        .unwrap();
+
+    assert_eq!(gateway.intents(), intents);
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_close_4014_is_disallowed_intents() {
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+    use tokio_tungstenite::tungstenite::protocol::frame::coding::CloseCode;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        next_json(&mut ws).await;
+
+        ws.close(Some(CloseFrame {
+            code: CloseCode::from(4014),
+            reason: "Disallowed intent(s).".into(),
+        }))
+        .await
+        .unwrap();
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    let error = gateway.next_event().await.unwrap_err();
+
+    assert!(matches!(error, GatewayError::DisallowedIntents));
+    assert!(error.is_fatal());
+
+    server_handle.await.unwrap();
+}
+
+async fn close_with(ws: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>, code: u16) {
+    use tokio_tungstenite::tungstenite::protocol::CloseFrame;
+
+    ws.close(Some(CloseFrame {
+        code: code.into(),
+        reason: "".into(),
+    }))
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_supervisor_stops_on_unrecoverable_close_codes() {
+    use client::gateway::{ConnectionState, GatewaySupervisor};
+
+    for code in [4010, 4011, 4012, 4013, 4014] {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let server_handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+
+            send_json(
+                &mut ws,
+                json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+            )
+            .await;
+            next_json(&mut ws).await;
+            close_with(&mut ws, code).await;
+        });
+
+        let mut supervisor = GatewaySupervisor::with_url(Token::bot("test-token"), &url);
+
+        assert!(matches!(
+            supervisor.next_event().await.unwrap(),
+            Event::ConnectionState(ConnectionState::Connecting)
+        ));
+        assert!(matches!(
+            supervisor.next_event().await.unwrap(),
+            Event::ConnectionState(ConnectionState::Connected)
+        ));
+
+        let error = supervisor.next_event().await.unwrap_err();
+        assert!(error.is_fatal(), "close code {} gave {:?}", code, error);
+
+        server_handle.await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn test_supervisor_identifies_again_after_invalid_sequence() {
+    use client::gateway::{ConnectionState, GatewaySupervisor};
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let hello = json!({ "op": 10, "d": { "heartbeat_interval": 45000 } });
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut first = accept_async(stream).await.unwrap();
+        send_json(&mut first, hello.clone()).await;
+        assert_eq!(next_json(&mut first).await["op"], 2);
+        send_json(&mut first, ready(&resume_url)).await;
+        close_with(&mut first, 4007).await;
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut second = accept_async(stream).await.unwrap();
+        send_json(&mut second, hello).await;
+        assert_eq!(next_json(&mut second).await["op"], 2);
+        send_json(&mut second, ready(&resume_url)).await;
+    });
+
+    let mut supervisor = GatewaySupervisor::with_url(Token::bot("test-token"), &url);
+    supervisor.set_backoff(Duration::from_millis(10), Duration::from_millis(10));
+
+    let mut next = async || supervisor.next_event().await.unwrap();
+
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connecting)
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connected)
+    ));
+    assert!(matches!(next().await, Event::Ready { .. }));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Disconnected { .. })
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connecting)
+    ));
+    assert!(matches!(
+        next().await,
+        Event::ConnectionState(ConnectionState::Connected)
+    ));
+    assert!(matches!(next().await, Event::Ready { .. }));
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_skips_undecodable_dispatch() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+        )
+        .await;
+        next_json(&mut ws).await;
+
+        send_json(
+            &mut ws,
+            json!({ "op": 0, "t": "INTERACTION_CREATE", "s": 1, "d": { "id": "not-an-id" } }),
+        )
+        .await;
+        send_json(
+            &mut ws,
+            json!({
+                "op": 0,
+                "t": "MESSAGE_CREATE",
+                "s": 2,
+                "d": {
+                    "id": 7,
+                    "channel_id": 20,
+                    "content": "still connected",
+                    "timestamp": "2026-02-17T12:00:00Z",
+                    "author": { "id": 42, "username": "alice", "discriminator": "0" }
+                }
+            }),
+        )
+        .await;
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    let event = gateway.next_event().await.unwrap();
+    let Event::MessageCreate(message) = event else {
+        panic!("Expected MESSAGE_CREATE event, got {:?}", event);
+    };
+
+    assert_eq!(message.content, "still connected");
+
+    server_handle.await.unwrap();
+}
+
+#[tokio::test]
+async fn test_gateway_keeps_heartbeating_during_invalid_session_wait() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+    let resume_url = url.clone();
+
+    let server_handle = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+
+        send_json(
+            &mut ws,
+            json!({ "op": 10, "d": { "heartbeat_interval": 50 } }),
+        )
+        .await;
+
+        let mut identified = false;
+
+        loop {
+            let msg = ws.next().await.unwrap().unwrap();
+            let payload: serde_json::Value = serde_json::from_str(msg.to_text().unwrap()).unwrap();
+
+            match payload["op"].as_u64() {
+                Some(1) => send_json(&mut ws, json!({ "op": 11 })).await,
+                Some(2) if !identified => {
+                    identified = true;
+                    send_json(&mut ws, json!({ "op": 9, "d": false })).await;
+                }
+                Some(2) => {
+                    send_json(&mut ws, ready(&resume_url)).await;
+                    break;
+                }
+                other => panic!("Unexpected op {:?}", other),
+            }
+        }
+    });
+
+    let mut gateway = Gateway::connect_with_url(Token::bot("test-token"), &url)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        gateway.next_event().await.unwrap(),
+        Event::Ready { .. }
+    ));
+
+    server_handle.await.unwrap();
+}
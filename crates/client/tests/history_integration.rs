@@ -0,0 +1,186 @@
+use chrono::{Duration, TimeZone, Utc};
+use client::auth::Token;
+use client::client::HttpClient;
+use client::pagination::{HistoryBound, HistoryStart, message_history};
+use futures_util::{StreamExt, TryStreamExt};
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::channel::ChannelId;
+use types::message::{Message, MessageId};
+
+fn messages(ids: impl Iterator<Item = u64>) -> String {
+    let base = Utc.with_ymd_and_hms(2026, 2, 17, 12, 0, 0).unwrap();
+    let messages: Vec<_> = ids
+        .map(|id| {
+            json!({
+                "id": id,
+                "author": { "id": 1, "username": "oncall", "discriminator": "0", "global_name": null },
+                "content": format!("message {}", id),
+                "channel_id": 123,
+                "timestamp": base + Duration::minutes(id as i64)
+            })
+        })
+        .collect();
+
+    serde_json::to_string(&messages).unwrap()
+}
+
+fn client(server: &Server) -> HttpClient {
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+    client
+}
+
+#[tokio::test]
+async fn test_history_pages_backwards_until_exhausted() {
+    let mut server = Server::new_async().await;
+
+    let _first = server
+        .mock("GET", "/channels/123/messages?limit=100")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages((51..=150).rev()))
+        .create_async()
+        .await;
+
+    let _second = server
+        .mock("GET", "/channels/123/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("before".into(), "51".into()),
+            Matcher::UrlEncoded("limit".into(), "100".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages((1..=50).rev()))
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let history: Vec<Message> = message_history(
+        &client,
+        ChannelId(123),
+        HistoryStart::Latest,
+        HistoryBound::Unbounded,
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(history.len(), 150);
+    assert_eq!(history.first().unwrap().id, MessageId(150));
+    assert_eq!(history.last().unwrap().id, MessageId(1));
+}
+
+#[tokio::test]
+async fn test_history_stops_at_count() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("GET", "/channels/123/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("before".into(), "500".into()),
+            Matcher::UrlEncoded("limit".into(), "3".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages([499, 498, 497].into_iter()))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let history: Vec<Message> = message_history(
+        &client,
+        ChannelId(123),
+        HistoryStart::Before(MessageId(500)),
+        HistoryBound::Count(3),
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(history.len(), 3);
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_history_forwards_until_message() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/messages")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("after".into(), "10".into()),
+            Matcher::UrlEncoded("limit".into(), "100".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages((11..=20).rev()))
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let ids: Vec<MessageId> = message_history(
+        &client,
+        ChannelId(123),
+        HistoryStart::After(MessageId(10)),
+        HistoryBound::Message(MessageId(14)),
+    )
+    .map(|m| m.unwrap().id)
+    .collect()
+    .await;
+
+    assert_eq!(ids, vec![MessageId(11), MessageId(12), MessageId(13)]);
+}
+
+#[tokio::test]
+async fn test_history_stops_at_timestamp() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/messages?limit=100")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(messages((1..=10).rev()))
+        .create_async()
+        .await;
+
+    let since = Utc.with_ymd_and_hms(2026, 2, 17, 12, 8, 0).unwrap();
+    let client = client(&server);
+    let history: Vec<Message> = message_history(
+        &client,
+        ChannelId(123),
+        HistoryStart::Latest,
+        HistoryBound::Timestamp(since),
+    )
+    .try_collect()
+    .await
+    .unwrap();
+
+    assert_eq!(history.len(), 3);
+    assert!(history.iter().all(|m| m.timestamp >= since));
+}
+
+#[tokio::test]
+async fn test_history_ends_after_error() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/messages?limit=100")
+        .with_status(403)
+        .create_async()
+        .await;
+
+    let client = client(&server);
+    let results: Vec<_> = message_history(
+        &client,
+        ChannelId(123),
+        HistoryStart::Latest,
+        HistoryBound::Unbounded,
+    )
+    .collect()
+    .await;
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
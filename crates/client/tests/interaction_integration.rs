@@ -0,0 +1,245 @@
+use client::auth::Token;
+use client::bodies::{EditWebhookMessage, InteractionMessage, InteractionResponse, Modal};
+use client::client::HttpClient;
+use client::errors::client::ClientError;
+use client::traits::rest_client::RestClient;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::command::ApplicationId;
+use types::component::{ButtonStyle, Component, TextInputStyle};
+use types::interaction::{Interaction, InteractionType};
+use types::message::MessageId;
+
+fn message_json(id: u64, content: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "content": content,
+        "channel_id": 20,
+        "author": { "id": 5, "username": "bot", "discriminator": "0" },
+        "timestamp": "2026-02-17T12:00:00Z"
+    })
+}
+
+#[tokio::test]
+async fn test_respond_with_ephemeral_message_and_buttons() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/interactions/900/interaction-token/callback")
+        .match_body(Matcher::Json(json!({
+            "type": 4,
+            "data": {
+                "content": "Deploy to prod?",
+                "flags": 64,
+                "components": [{
+                    "type": 1,
+                    "components": [
+                        { "type": 2, "style": 3, "custom_id": "confirm", "label": "Deploy" },
+                        { "type": 2, "style": 2, "custom_id": "cancel", "label": "Cancel" }
+                    ]
+                }]
+            }
+        })))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let message = InteractionMessage::with_content("Deploy to prod?")
+        .action_row(vec![
+            Component::button(ButtonStyle::Success, "confirm", "Deploy"),
+            Component::button(ButtonStyle::Secondary, "cancel", "Cancel"),
+        ])
+        .ephemeral();
+
+    let result = client
+        .create_interaction_response(
+            900.into(),
+            "interaction-token",
+            &InteractionResponse::message(message),
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_respond_with_modal() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/interactions/900/interaction-token/callback")
+        .match_body(Matcher::Json(json!({
+            "type": 9,
+            "data": {
+                "custom_id": "report",
+                "title": "Report a problem",
+                "components": [{
+                    "type": 1,
+                    "components": [
+                        { "type": 4, "style": 2, "custom_id": "details", "label": "Details" }
+                    ]
+                }]
+            }
+        })))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let modal = Modal::new("report", "Report a problem").text_input(Component::text_input(
+        TextInputStyle::Paragraph,
+        "details",
+        "Details",
+    ));
+
+    let result = client
+        .create_interaction_response(
+            900.into(),
+            "interaction-token",
+            &InteractionResponse::modal(modal),
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_empty_modal_is_rejected_before_sending() {
+    let server = Server::new_async().await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let result = client
+        .create_interaction_response(
+            900.into(),
+            "interaction-token",
+            &InteractionResponse::modal(Modal::new("report", "Report a problem")),
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(ClientError::InvalidInteractionResponse(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_deferred_response_then_edit_and_followup() {
+    let mut server = Server::new_async().await;
+
+    let _defer = server
+        .mock("POST", "/interactions/900/interaction-token/callback")
+        .match_body(Matcher::Json(json!({ "type": 5 })))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let _edit = server
+        .mock("PATCH", "/webhooks/5/interaction-token/messages/@original")
+        .match_body(Matcher::Json(json!({ "content": "Deployed" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(message_json(300, "Deployed").to_string())
+        .create_async()
+        .await;
+
+    let _followup = server
+        .mock("POST", "/webhooks/5/interaction-token")
+        .match_body(Matcher::Json(
+            json!({ "content": "Logs attached", "flags": 64 }),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(message_json(301, "Logs attached").to_string())
+        .create_async()
+        .await;
+
+    let _delete = server
+        .mock("DELETE", "/webhooks/5/interaction-token/messages/301")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let app = ApplicationId(5);
+    let token = "interaction-token";
+
+    client
+        .create_interaction_response(
+            900.into(),
+            token,
+            &InteractionResponse::deferred_message(false),
+        )
+        .await
+        .unwrap();
+    let edited = client
+        .edit_original_response(app, token, &EditWebhookMessage::with_content("Deployed"))
+        .await
+        .unwrap();
+    let followup = client
+        .create_followup_message(
+            app,
+            token,
+            &InteractionMessage::with_content("Logs attached").ephemeral(),
+        )
+        .await
+        .unwrap();
+    let deleted = client
+        .delete_followup_message(app, token, MessageId(301))
+        .await;
+
+    assert_eq!(edited.content, "Deployed");
+    assert_eq!(followup.id, MessageId(301));
+    assert!(deleted.is_ok());
+}
+
+#[test]
+fn test_deserialize_modal_submit_interaction() {
+    let interaction: Interaction = serde_json::from_value(json!({
+        "id": 901,
+        "application_id": 5,
+        "type": 5,
+        "token": "interaction-token",
+        "user": { "id": 42, "username": "alice", "discriminator": "0" },
+        "data": {
+            "custom_id": "report",
+            "components": [{
+                "type": 1,
+                "components": [{ "type": 4, "custom_id": "details", "value": "It broke" }]
+            }]
+        }
+    }))
+    .unwrap();
+
+    assert_eq!(interaction.kind, InteractionType::ModalSubmit);
+    assert_eq!(interaction.author().unwrap().username, "alice");
+    assert_eq!(
+        interaction.modal_data().unwrap().value("details"),
+        Some("It broke")
+    );
+}
+
+#[test]
+fn test_deserialize_component_interaction() {
+    let interaction: Interaction = serde_json::from_value(json!({
+        "id": 902,
+        "application_id": 5,
+        "type": 3,
+        "token": "interaction-token",
+        "data": { "custom_id": "env", "component_type": 3, "values": ["staging"] }
+    }))
+    .unwrap();
+
+    let data = interaction.component_data().unwrap();
+
+    assert_eq!(data.custom_id, "env");
+    assert_eq!(data.values, ["staging"]);
+}
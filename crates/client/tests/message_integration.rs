@@ -1,21 +1,20 @@
 use chrono::Utc;
+use client::auth::Token;
+use client::bodies::{AllowedMentions, CreateMessage};
 use client::client::HttpClient;
 use client::errors::client::ClientError;
+use client::files::FileUpload;
 use client::queries::GetMessagesQuery;
 use client::traits::rest_client::RestClient;
-use mockito::Server;
+use mockito::{Matcher, Server};
 use types::channel::ChannelId;
-use types::message::Message;
+use types::embed::{Embed, EmbedField};
+use types::message::{Message, MessageId, MessageReference};
 use types::user::User;
 
-#[tokio::test]
-async fn test_send_message_success() {
-    let mut server = Server::new_async().await;
-    let channel_id = ChannelId(123);
-    let content = "Hello from test!";
-
-    let mock_message = Message {
-        id: 1.into(),
+fn mock_message(id: u64, channel_id: ChannelId, content: &str) -> Message {
+    Message {
+        id: id.into(),
         author: User {
             id: 1.into(),
             username: "testuser".into(),
@@ -26,21 +25,37 @@ async fn test_send_message_success() {
         content: content.into(),
         channel_id,
         timestamp: Utc::now(),
-    };
+        reactions: Vec::new(),
+        attachments: Vec::new(),
+        embeds: Vec::new(),
+        pinned: false,
+        message_reference: None,
+        referenced_message: None,
+    }
+}
+
+#[tokio::test]
+async fn test_send_message_success() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+    let content = "Hello from test!";
+
+    let message = mock_message(1, channel_id, content);
 
     let _m = server
         .mock("POST", "/channels/123/messages")
         .match_header("authorization", "Bot test-token")
-        .match_body(mockito::Matcher::Json(
-            serde_json::json!({ "content": content }),
-        ))
+        .match_body(Matcher::Json(serde_json::json!({
+            "content": content,
+            "allowed_mentions": { "parse": ["roles", "users"], "replied_user": true }
+        })))
         .with_status(201)
         .with_header("content-type", "application/json")
-        .with_body(serde_json::to_string(&mock_message).unwrap())
+        .with_body(serde_json::to_string(&message).unwrap())
         .create_async()
         .await;
 
-    let mut client = HttpClient::new("test-token".into());
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
     client.set_base_url(server.url());
 
     let result = client.send_message(channel_id, content).await;
@@ -54,7 +69,7 @@ async fn test_send_message_success() {
 #[tokio::test]
 async fn test_send_message_too_long() {
     let content = "a".repeat(2001);
-    let client = HttpClient::new("test-token".into());
+    let client = HttpClient::new(Token::bot("test-token")).unwrap();
     let channel_id = ChannelId(123);
 
     let result = client.send_message(channel_id, &content).await;
@@ -71,19 +86,7 @@ async fn test_send_message_retry_success() {
     let channel_id = ChannelId(123);
     let content = "Retry test";
 
-    let mock_message = Message {
-        id: 2.into(),
-        author: User {
-            id: 1.into(),
-            username: "testuser".into(),
-            discriminator: "0000".into(),
-            global_name: None,
-            email: "test@test.com".into(),
-        },
-        content: content.into(),
-        channel_id,
-        timestamp: Utc::now(),
-    };
+    let message = mock_message(2, channel_id, content);
 
     let _m1 = server
         .mock("POST", "/channels/123/messages")
@@ -96,12 +99,12 @@ async fn test_send_message_retry_success() {
         .mock("POST", "/channels/123/messages")
         .with_status(201)
         .with_header("content-type", "application/json")
-        .with_body(serde_json::to_string(&mock_message).unwrap())
+        .with_body(serde_json::to_string(&message).unwrap())
         .expect(1)
         .create_async()
         .await;
 
-    let mut client = HttpClient::new("test-token".into());
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
     client.set_base_url(server.url());
 
     let result = client.send_message(channel_id, content).await;
@@ -116,32 +119,8 @@ async fn test_get_messages_success() {
     let channel_id = ChannelId(456);
 
     let mock_messages = vec![
-        Message {
-            id: 10.into(),
-            author: User {
-                id: 1.into(),
-                username: "testuser".into(),
-                discriminator: "0000".into(),
-                global_name: None,
-                email: "test@test.com".into(),
-            },
-            content: "Message 1".into(),
-            channel_id,
-            timestamp: Utc::now(),
-        },
-        Message {
-            id: 11.into(),
-            author: User {
-                id: 1.into(),
-                username: "testuser".into(),
-                discriminator: "0000".into(),
-                global_name: None,
-                email: "test@test.com".into(),
-            },
-            content: "Message 2".into(),
-            channel_id,
-            timestamp: Utc::now(),
-        },
+        mock_message(10, channel_id, "Message 1"),
+        mock_message(11, channel_id, "Message 2"),
     ];
 
     let _m = server
@@ -152,7 +131,7 @@ async fn test_get_messages_success() {
         .create_async()
         .await;
 
-    let mut client = HttpClient::new("test-token".into());
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
     client.set_base_url(server.url());
 
     let result = client
@@ -165,3 +144,334 @@ async fn test_get_messages_success() {
     assert_eq!(msgs[0].content, "Message 1");
     assert_eq!(msgs[1].content, "Message 2");
 }
+
+#[tokio::test]
+async fn test_edit_message_success() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+    let content = "Fixed the typo";
+
+    let _m = server
+        .mock("PATCH", "/channels/123/messages/7")
+        .match_body(Matcher::Json(serde_json::json!({ "content": content })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&mock_message(7, channel_id, content)).unwrap())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let msg = client
+        .edit_message(channel_id, MessageId(7), content)
+        .await
+        .unwrap();
+
+    assert_eq!(msg.id, MessageId(7));
+    assert_eq!(msg.content, content);
+}
+
+#[tokio::test]
+async fn test_edit_message_too_long() {
+    let content = "a".repeat(2001);
+    let client = HttpClient::new(Token::bot("test-token")).unwrap();
+
+    let result = client
+        .edit_message(ChannelId(123), MessageId(7), &content)
+        .await;
+
+    match result {
+        Err(ClientError::MessageTooLong) => (),
+        _ => panic!("Expected MessageTooLong error"),
+    }
+}
+
+#[tokio::test]
+async fn test_delete_message_success() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("DELETE", "/channels/123/messages/7")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let result = client.delete_message(ChannelId(123), MessageId(7)).await;
+
+    assert!(result.is_ok());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_delete_messages_success() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("POST", "/channels/123/messages/bulk-delete")
+        .match_body(Matcher::Json(serde_json::json!({ "messages": [7, 8, 9] })))
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let ids = [MessageId(7), MessageId(8), MessageId(9)];
+    let result = client.bulk_delete_messages(ChannelId(123), &ids).await;
+
+    assert!(result.is_ok());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_bulk_delete_messages_invalid_count() {
+    let client = HttpClient::new(Token::bot("test-token")).unwrap();
+
+    let result = client
+        .bulk_delete_messages(ChannelId(123), &[MessageId(7)])
+        .await;
+
+    match result {
+        Err(ClientError::InvalidBulkDeleteCount { count: 1 }) => (),
+        other => panic!("Expected InvalidBulkDeleteCount error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_send_files_success() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+
+    let mut message = serde_json::to_value(mock_message(3, channel_id, "Build log")).unwrap();
+    message["attachments"] = serde_json::json!([{
+        "id": 99,
+        "filename": "build.log",
+        "size": 8,
+        "url": "https://cdn.discordapp.com/attachments/123/99/build.log",
+        "content_type": "text/plain"
+    }]);
+
+    let _m = server
+        .mock("POST", "/channels/123/messages")
+        .match_header(
+            "content-type",
+            Matcher::Regex("^multipart/form-data; boundary=".into()),
+        )
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="payload_json""#.into()),
+            Matcher::Regex(r#""content":"Build log""#.into()),
+            Matcher::Regex(r#"name="files\[0\]"; filename="build.log""#.into()),
+            Matcher::Regex("build ok".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(message.to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let files = [FileUpload::from_bytes("build.log", "build ok").with_content_type("text/plain")];
+    let msg = client
+        .send_files(
+            channel_id,
+            &CreateMessage::with_content("Build log"),
+            &files,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(msg.attachments.len(), 1);
+    assert_eq!(msg.attachments[0].filename, "build.log");
+    assert_eq!(msg.attachments[0].size, 8);
+    assert_eq!(
+        msg.attachments[0].content_type.as_deref(),
+        Some("text/plain")
+    );
+}
+
+#[tokio::test]
+async fn test_send_files_too_many() {
+    let client = HttpClient::new(Token::bot("test-token")).unwrap();
+    let files: Vec<FileUpload> = (0..11)
+        .map(|i| FileUpload::from_bytes(format!("{}.png", i), vec![0u8]))
+        .collect();
+
+    let result = client
+        .send_files(ChannelId(123), &CreateMessage::default(), &files)
+        .await;
+
+    match result {
+        Err(ClientError::TooManyAttachments { count: 11 }) => (),
+        other => panic!("Expected TooManyAttachments error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_create_message_reply() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+
+    let original = mock_message(7, channel_id, "Deploy started");
+    let mut reply = mock_message(8, channel_id, "Deploy finished");
+    reply.message_reference = Some(MessageReference::to_message(MessageId(7)));
+    reply.referenced_message = Some(Box::new(original));
+
+    let _m = server
+        .mock("POST", "/channels/123/messages")
+        .match_body(Matcher::Json(serde_json::json!({
+            "content": "Deploy finished",
+            "message_reference": { "message_id": 7 },
+            "allowed_mentions": { "parse": [], "replied_user": false }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&reply).unwrap())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let message = CreateMessage::with_content("Deploy finished")
+        .allowed_mentions(AllowedMentions::none())
+        .reply_to(MessageId(7), false);
+    let msg = client.create_message(channel_id, &message).await.unwrap();
+
+    assert_eq!(
+        msg.message_reference.and_then(|r| r.message_id),
+        Some(MessageId(7))
+    );
+    assert_eq!(msg.referenced_message.unwrap().content, "Deploy started");
+}
+
+#[tokio::test]
+async fn test_create_message_with_embed() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+
+    let embed = Embed {
+        title: Some("Build #42".into()),
+        color: Some(0x2ecc71),
+        fields: vec![EmbedField {
+            name: "Status".into(),
+            value: "passed".into(),
+            inline: true,
+        }],
+        ..Default::default()
+    };
+
+    let mut response = mock_message(9, channel_id, "");
+    response.embeds = vec![embed.clone()];
+
+    let _m = server
+        .mock("POST", "/channels/123/messages")
+        .match_body(Matcher::Json(serde_json::json!({
+            "embeds": [{
+                "title": "Build #42",
+                "color": 0x2ecc71,
+                "fields": [{ "name": "Status", "value": "passed", "inline": true }]
+            }],
+            "allowed_mentions": { "parse": ["roles", "users"], "replied_user": true }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&response).unwrap())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let msg = client
+        .create_message(channel_id, &CreateMessage::default().embed(embed.clone()))
+        .await
+        .unwrap();
+
+    assert_eq!(msg.embeds, vec![embed]);
+}
+
+#[tokio::test]
+async fn test_create_message_invalid_embed() {
+    let client = HttpClient::new(Token::bot("test-token")).unwrap();
+    let embed = Embed {
+        title: Some("t".repeat(300)),
+        ..Default::default()
+    };
+
+    let result = client
+        .create_message(ChannelId(123), &CreateMessage::default().embed(embed))
+        .await;
+
+    match result {
+        Err(ClientError::InvalidEmbed(_)) => (),
+        other => panic!("Expected InvalidEmbed error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_get_pinned_messages() {
+    let mut server = Server::new_async().await;
+    let channel_id = ChannelId(123);
+
+    let mut runbook = mock_message(5, channel_id, "Runbook: restart the workers");
+    runbook.pinned = true;
+
+    let _m = server
+        .mock("GET", "/channels/123/pins")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&vec![runbook]).unwrap())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let pins = client.get_pinned_messages(channel_id).await.unwrap();
+
+    assert_eq!(pins.len(), 1);
+    assert!(pins[0].pinned);
+    assert_eq!(pins[0].content, "Runbook: restart the workers");
+}
+
+#[tokio::test]
+async fn test_pin_and_unpin_message() {
+    let mut server = Server::new_async().await;
+
+    let pin = server
+        .mock("PUT", "/channels/123/pins/5")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let unpin = server
+        .mock("DELETE", "/channels/123/pins/5")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    assert!(
+        client
+            .pin_message(ChannelId(123), MessageId(5))
+            .await
+            .is_ok()
+    );
+    assert!(
+        client
+            .unpin_message(ChannelId(123), MessageId(5))
+            .await
+            .is_ok()
+    );
+
+    pin.assert_async().await;
+    unpin.assert_async().await;
+}
@@ -0,0 +1,181 @@
+use client::auth::Token;
+use client::client::HttpClient;
+use client::errors::client::ClientError;
+use client::queries::GetMessagesQuery;
+use client::traits::rest_client::RestClient;
+use mockito::Server;
+use std::time::{Duration, Instant};
+use types::channel::ChannelId;
+use types::guild::GuildId;
+
+#[tokio::test]
+async fn test_request_waits_for_exhausted_bucket() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-bucket", "messages")
+        .with_header("x-ratelimit-limit", "5")
+        .with_header("x-ratelimit-remaining", "0")
+        .with_header("x-ratelimit-reset-after", "0.3")
+        .with_body("[]")
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let query = GetMessagesQuery::default();
+    client.get_messages(ChannelId(123), query).await.unwrap();
+
+    let start = Instant::now();
+    client.get_messages(ChannelId(123), query).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[tokio::test]
+async fn test_other_channels_are_not_delayed() {
+    let mut server = Server::new_async().await;
+
+    let _limited = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-bucket", "messages")
+        .with_header("x-ratelimit-remaining", "0")
+        .with_header("x-ratelimit-reset-after", "5")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let _other = server
+        .mock("GET", "/channels/456/messages")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let query = GetMessagesQuery::default();
+    client.get_messages(ChannelId(123), query).await.unwrap();
+
+    let start = Instant::now();
+    client.get_messages(ChannelId(456), query).await.unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_is_retried() {
+    let mut server = Server::new_async().await;
+
+    let _limited = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(429)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"message": "You are being rate limited.", "retry_after": 0.25, "global": false}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let _ok = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let start = Instant::now();
+    let result = client
+        .get_messages(ChannelId(123), GetMessagesQuery::default())
+        .await;
+
+    assert!(result.is_ok());
+    assert!(start.elapsed() >= Duration::from_millis(250));
+}
+
+#[tokio::test]
+async fn test_rate_limit_budget_exhausted() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(429)
+        .with_header("content-type", "application/json")
+        .with_header("retry-after", "1")
+        .with_body(
+            r#"{"message": "You are being rate limited.", "retry_after": 0.5, "global": true}"#,
+        )
+        .expect(1)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+    client.set_max_rate_limit_retries(0);
+
+    let result = client
+        .get_messages(ChannelId(123), GetMessagesQuery::default())
+        .await;
+
+    match result {
+        Err(ClientError::RateLimited {
+            retry_after,
+            global,
+        }) => {
+            assert_eq!(retry_after, 0.5);
+            assert!(global);
+        }
+        other => panic!("Expected RateLimited error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_global_rate_limit_blocks_other_routes() {
+    let mut server = Server::new_async().await;
+
+    let _limited = server
+        .mock("GET", "/channels/123/messages")
+        .with_status(429)
+        .with_header("content-type", "application/json")
+        .with_body(
+            r#"{"message": "You are being rate limited.", "retry_after": 0.3, "global": true}"#,
+        )
+        .create_async()
+        .await;
+
+    let _other = server
+        .mock("GET", "/guilds/1/channels")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+    client.set_max_rate_limit_retries(0);
+
+    let _ = client
+        .get_messages(ChannelId(123), GetMessagesQuery::default())
+        .await;
+
+    let start = Instant::now();
+    client.get_channels(GuildId(1)).await.unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(250));
+}
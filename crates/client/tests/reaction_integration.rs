@@ -0,0 +1,119 @@
+use client::auth::Token;
+use client::client::HttpClient;
+use client::queries::GetReactionsQuery;
+use client::traits::rest_client::RestClient;
+use mockito::Server;
+use types::channel::ChannelId;
+use types::emoji::{EmojiId, ReactionType};
+use types::message::MessageId;
+use types::user::UserId;
+
+#[tokio::test]
+async fn test_add_unicode_reaction() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("PUT", "/channels/123/messages/7/reactions/%F0%9F%91%8D/@me")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let result = client
+        .add_reaction(ChannelId(123), MessageId(7), &ReactionType::from("👍"))
+        .await;
+
+    assert!(result.is_ok());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_remove_own_custom_reaction() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("DELETE", "/channels/123/messages/7/reactions/shipit:42/@me")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let emoji = ReactionType::Custom {
+        name: "shipit".into(),
+        id: EmojiId(42),
+    };
+    let result = client
+        .remove_own_reaction(ChannelId(123), MessageId(7), &emoji)
+        .await;
+
+    assert!(result.is_ok());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_remove_user_reaction() {
+    let mut server = Server::new_async().await;
+
+    let m = server
+        .mock("DELETE", "/channels/123/messages/7/reactions/%E2%9C%85/99")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let result = client
+        .remove_user_reaction(
+            ChannelId(123),
+            MessageId(7),
+            &ReactionType::from("✅"),
+            UserId(99),
+        )
+        .await;
+
+    assert!(result.is_ok());
+    m.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_reactions() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock(
+            "GET",
+            "/channels/123/messages/7/reactions/%F0%9F%91%8D?limit=10",
+        )
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!([
+                { "id": 1, "username": "alice", "discriminator": "0", "global_name": null },
+                { "id": 2, "username": "bob", "discriminator": "0", "global_name": null }
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let users = client
+        .get_reactions(
+            ChannelId(123),
+            MessageId(7),
+            &ReactionType::from("👍"),
+            GetReactionsQuery::with_limit(10),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 2);
+    assert_eq!(users[0].username, "alice");
+}
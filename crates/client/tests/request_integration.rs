@@ -0,0 +1,142 @@
+use client::auth::Token;
+use client::client::HttpClient;
+use client::errors::client::ClientError;
+use client::request::Request;
+use client::routes::Route;
+use mockito::{Matcher, Server};
+use reqwest::header::{HeaderName, HeaderValue};
+use serde::Serialize;
+use std::time::Duration;
+use types::channel::ChannelId;
+use types::message::MessageId;
+
+fn client_for(server: &Server) -> HttpClient {
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+    client
+}
+
+#[tokio::test]
+async fn test_send_with_audit_log_reason() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/channels/1/messages/2")
+        .match_header("x-audit-log-reason", "Removing%20spam")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let request = Request::new(Route::DeleteMessage {
+        channel_id: ChannelId(1),
+        message_id: MessageId(2),
+    })
+    .reason("Removing spam");
+
+    let result: Result<(), ClientError> = client.send(request).await;
+
+    assert!(result.is_ok());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_custom_header() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/channels/1/pins")
+        .match_header("x-trace-id", "abc123")
+        .with_status(200)
+        .with_body("[]")
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let request = Request::new(Route::GetPins {
+        channel_id: ChannelId(1),
+    })
+    .header(
+        HeaderName::from_static("x-trace-id"),
+        HeaderValue::from_static("abc123"),
+    );
+
+    let result: Result<Vec<serde_json::Value>, ClientError> = client.send(request).await;
+
+    assert!(result.unwrap().is_empty());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_owned_body_is_resent_on_retry() {
+    #[derive(Serialize)]
+    struct Announcement {
+        content: String,
+        tags: Vec<String>,
+    }
+
+    let mut server = Server::new_async().await;
+    let body = Matcher::Json(serde_json::json!({
+        "content": "Deploy finished",
+        "tags": ["prod", "eu"]
+    }));
+
+    let failure = server
+        .mock("POST", "/channels/1/messages")
+        .match_body(body.clone())
+        .with_status(502)
+        .expect(1)
+        .create_async()
+        .await;
+
+    let success = server
+        .mock("POST", "/channels/1/messages")
+        .match_body(body)
+        .with_status(200)
+        .with_body("{}")
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let announcement = Announcement {
+        content: "Deploy finished".to_string(),
+        tags: vec!["prod".to_string(), "eu".to_string()],
+    };
+
+    let request = Request::new(Route::CreateMessage {
+        channel_id: ChannelId(1),
+    })
+    .json(&announcement);
+
+    let result: Result<serde_json::Value, ClientError> = client.send(request).await;
+
+    assert!(result.is_ok());
+    failure.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_timeout() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/1/pins")
+        .with_status(200)
+        .with_chunked_body(|_| {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok(())
+        })
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let request = Request::new(Route::GetPins {
+        channel_id: ChannelId(1),
+    })
+    .timeout(Duration::from_millis(50));
+
+    let result: Result<Vec<serde_json::Value>, ClientError> = client.send(request).await;
+
+    assert!(matches!(result, Err(ClientError::Network(_))));
+}
@@ -0,0 +1,236 @@
+use chrono::{TimeZone, Utc};
+use client::auth::Token;
+use client::bodies::StartThread;
+use client::client::HttpClient;
+use client::queries::GetArchivedThreadsQuery;
+use client::traits::rest_client::RestClient;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::channel::{ChannelId, ChannelType};
+use types::guild::GuildId;
+use types::message::MessageId;
+
+fn thread_json(id: u64, name: &str, kind: u8, archived: bool) -> serde_json::Value {
+    json!({
+        "id": id,
+        "type": kind,
+        "name": name,
+        "guild_id": 1,
+        "parent_id": 123,
+        "owner_id": 42,
+        "message_count": 3,
+        "member_count": 2,
+        "thread_metadata": {
+            "archived": archived,
+            "auto_archive_duration": 1440,
+            "archive_timestamp": "2026-02-17T12:00:00Z",
+            "locked": false
+        }
+    })
+}
+
+#[tokio::test]
+async fn test_start_thread_from_message() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/123/messages/7/threads")
+        .match_body(Matcher::Json(json!({
+            "name": "incident-42",
+            "auto_archive_duration": 1440
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(thread_json(900, "incident-42", 11, false).to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let thread = StartThread {
+        auto_archive_duration: Some(1440),
+        ..StartThread::new("incident-42")
+    };
+    let channel = client
+        .start_thread_from_message(ChannelId(123), MessageId(7), &thread)
+        .await
+        .unwrap();
+
+    assert_eq!(channel.id, ChannelId(900));
+    assert_eq!(channel.kind, ChannelType::PublicThread);
+    assert_eq!(channel.parent_id, Some(ChannelId(123)));
+    assert!(!channel.thread_metadata.unwrap().archived);
+}
+
+#[tokio::test]
+async fn test_start_thread_defaults_to_public() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/123/threads")
+        .match_body(Matcher::Json(json!({ "name": "standup", "type": 11 })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(thread_json(901, "standup", 11, false).to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let channel = client
+        .start_thread(ChannelId(123), &StartThread::new("standup"))
+        .await
+        .unwrap();
+
+    assert!(channel.kind.is_thread());
+}
+
+#[tokio::test]
+async fn test_start_private_thread() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/channels/123/threads")
+        .match_body(Matcher::Json(json!({ "name": "secret", "type": 12 })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(thread_json(902, "secret", 12, false).to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let channel = client
+        .start_thread(ChannelId(123), &StartThread::new("secret").private())
+        .await
+        .unwrap();
+
+    assert_eq!(channel.kind, ChannelType::PrivateThread);
+}
+
+#[tokio::test]
+async fn test_get_active_threads() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/guilds/1/threads/active")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "threads": [thread_json(900, "incident-42", 11, false)],
+                "members": [{
+                    "id": 900,
+                    "user_id": 42,
+                    "join_timestamp": "2026-02-17T12:00:00Z",
+                    "flags": 0
+                }]
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let list = client.get_active_threads(GuildId(1)).await.unwrap();
+
+    assert_eq!(list.threads.len(), 1);
+    assert_eq!(list.members.len(), 1);
+    assert!(!list.has_more);
+}
+
+#[tokio::test]
+async fn test_get_public_archived_threads_paginates() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/threads/archived/public")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("before".into(), "2026-02-17T12:00:00Z".into()),
+            Matcher::UrlEncoded("limit".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "threads": [
+                    thread_json(800, "old-1", 11, true),
+                    thread_json(801, "old-2", 11, true)
+                ],
+                "members": [],
+                "has_more": true
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let query = GetArchivedThreadsQuery {
+        before: Some(Utc.with_ymd_and_hms(2026, 2, 17, 12, 0, 0).unwrap()),
+        limit: Some(2),
+    };
+    let list = client
+        .get_public_archived_threads(ChannelId(123), query)
+        .await
+        .unwrap();
+
+    assert_eq!(list.threads.len(), 2);
+    assert!(list.has_more);
+}
+
+#[tokio::test]
+async fn test_get_private_archived_threads() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/channels/123/threads/archived/private?limit=50")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "threads": [], "members": [], "has_more": false }).to_string())
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let list = client
+        .get_private_archived_threads(ChannelId(123), GetArchivedThreadsQuery::with_limit(50))
+        .await
+        .unwrap();
+
+    assert!(list.threads.is_empty());
+}
+
+#[tokio::test]
+async fn test_join_and_leave_thread() {
+    let mut server = Server::new_async().await;
+
+    let join = server
+        .mock("PUT", "/channels/900/thread-members/@me")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let leave = server
+        .mock("DELETE", "/channels/900/thread-members/@me")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    assert!(client.join_thread(ChannelId(900)).await.is_ok());
+    assert!(client.leave_thread(ChannelId(900)).await.is_ok());
+
+    join.assert_async().await;
+    leave.assert_async().await;
+}
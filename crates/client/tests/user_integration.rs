@@ -0,0 +1,96 @@
+use client::auth::Token;
+use client::client::HttpClient;
+use client::queries::GetCurrentUserGuildsQuery;
+use client::traits::rest_client::RestClient;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::guild::GuildId;
+use types::user::UserId;
+
+#[tokio::test]
+async fn test_get_current_user() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/users/@me")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "id": 42,
+                "username": "discline-bot",
+                "discriminator": "0",
+                "global_name": null
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let user = client.get_current_user().await.unwrap();
+
+    assert_eq!(user.id, UserId(42));
+    assert_eq!(user.to_string(), "discline-bot");
+}
+
+#[tokio::test]
+async fn test_get_current_user_guilds_with_pagination() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/users/@me/guilds")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("after".into(), "100".into()),
+            Matcher::UrlEncoded("limit".into(), "2".into()),
+            Matcher::UrlEncoded("with_counts".into(), "true".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!([
+                {
+                    "id": 101,
+                    "name": "Rustaceans",
+                    "icon": null,
+                    "owner": true,
+                    "permissions": "2147483647",
+                    "features": ["COMMUNITY"],
+                    "approximate_member_count": 1200,
+                    "approximate_presence_count": 300
+                },
+                {
+                    "id": 102,
+                    "name": "Homelab",
+                    "icon": "a_1b2c",
+                    "owner": false,
+                    "permissions": "104324673",
+                    "features": []
+                }
+            ])
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let mut client = HttpClient::new(Token::bot("test-token")).unwrap();
+    client.set_base_url(server.url());
+
+    let guilds = client
+        .get_current_user_guilds(GetCurrentUserGuildsQuery {
+            after: Some(GuildId(100)),
+            limit: Some(2),
+            with_counts: Some(true),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(guilds.len(), 2);
+    assert!(guilds[0].owner);
+    assert_eq!(guilds[0].approximate_member_count, Some(1200));
+    assert_eq!(guilds[1].to_string(), "Homelab");
+    assert_eq!(guilds[1].description, None);
+}
@@ -0,0 +1,218 @@
+use client::bodies::{EditWebhookMessage, ExecuteWebhook};
+use client::errors::client::ClientError;
+use client::webhook::WebhookClient;
+use mockito::{Matcher, Server};
+use serde_json::json;
+use types::channel::ChannelId;
+use types::embed::Embed;
+use types::message::MessageId;
+
+fn webhook_message(id: u64, content: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "channel_id": 10,
+        "content": content,
+        "timestamp": "2026-02-17T12:00:00Z",
+        "author": {
+            "id": 555,
+            "username": "CI",
+            "discriminator": "0000",
+            "global_name": null
+        },
+        "message_reference": null,
+        "referenced_message": null
+    })
+}
+
+fn client_for(server: &Server) -> WebhookClient {
+    let mut client = WebhookClient::new("https://discord.com/api/webhooks/1/hook-token").unwrap();
+    client.set_base_url(server.url());
+    client
+}
+
+#[tokio::test]
+async fn test_execute_webhook_with_overrides() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/webhooks/1/hook-token")
+        .match_header("authorization", Matcher::Missing)
+        .match_query(Matcher::UrlEncoded("wait".into(), "true".into()))
+        .match_body(Matcher::Json(json!({
+            "content": "Build passed",
+            "username": "CI",
+            "avatar_url": "https://example.com/ci.png",
+            "embeds": [{ "title": "main #42" }]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(webhook_message(99, "Build passed").to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let message = ExecuteWebhook::with_content("Build passed")
+        .username("CI")
+        .avatar_url("https://example.com/ci.png")
+        .embed(Embed {
+            title: Some("main #42".to_string()),
+            ..Default::default()
+        });
+
+    let result = client.execute(&message, None).await.unwrap();
+
+    assert_eq!(result.id, MessageId(99));
+    assert_eq!(result.author.username, "CI");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_execute_webhook_in_thread() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/webhooks/1/hook-token")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("wait".into(), "true".into()),
+            Matcher::UrlEncoded("thread_id".into(), "77".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(webhook_message(100, "In thread").to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let result = client
+        .execute(
+            &ExecuteWebhook::with_content("In thread"),
+            Some(ChannelId(77)),
+        )
+        .await;
+
+    assert!(result.is_ok());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_edit_webhook_message() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("PATCH", "/webhooks/1/hook-token/messages/99")
+        .match_body(Matcher::Json(json!({ "content": "Build failed" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(webhook_message(99, "Build failed").to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let result = client
+        .edit_message(
+            MessageId(99),
+            &EditWebhookMessage::with_content("Build failed"),
+            None,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.content, "Build failed");
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_delete_webhook_message() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("DELETE", "/webhooks/1/hook-token/messages/99")
+        .with_status(204)
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let result = client.delete_message(MessageId(99), None).await;
+
+    assert!(result.is_ok());
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_execute_webhook_too_long() {
+    let server = Server::new_async().await;
+    let client = client_for(&server);
+
+    let result = client
+        .execute(&ExecuteWebhook::with_content("a".repeat(2001)), None)
+        .await;
+
+    assert!(matches!(result, Err(ClientError::MessageTooLong)));
+}
+
+#[tokio::test]
+async fn test_unknown_webhook_message_not_found() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("DELETE", "/webhooks/1/hook-token/messages/5")
+        .with_status(404)
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+    let result = client.delete_message(MessageId(5), None).await;
+
+    match result {
+        Err(ClientError::NotFound {
+            resource_type,
+            resource_id,
+            ..
+        }) => {
+            assert_eq!(resource_type, "Message");
+            assert_eq!(resource_id, "5");
+        }
+        other => panic!("Expected NotFound error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_debug_redacts_token_after_request() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("POST", "/webhooks/1/hook-token")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-bucket", "abcd")
+        .with_header("x-ratelimit-remaining", "4")
+        .with_header("x-ratelimit-reset-after", "1.0")
+        .with_body(webhook_message(99, "hi").to_string())
+        .create_async()
+        .await;
+
+    let client = client_for(&server);
+
+    client
+        .execute(&ExecuteWebhook::with_content("hi"), None)
+        .await
+        .unwrap();
+
+    assert!(!format!("{:?}", client).contains("hook-token"));
+}
+
+#[tokio::test]
+async fn test_network_error_hides_token() {
+    let mut client = WebhookClient::new("https://discord.com/api/webhooks/1/hook-token").unwrap();
+    client.set_base_url("http://127.0.0.1:1".to_string());
+
+    let error = client
+        .execute(&ExecuteWebhook::with_content("hi"), None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ClientError::Network(_)));
+    assert!(!error.to_string().contains("hook-token"));
+    assert!(!format!("{:?}", error).contains("hook-token"));
+}
@@ -0,0 +1,66 @@
+use client::{bodies::CreateCommand, errors::client::ClientError, traits::rest_client::RestClient};
+use types::{
+    command::{ApplicationCommand, ApplicationId},
+    guild::GuildId,
+};
+
+/// Replaces the application's commands with `definitions`, globally or in
+/// `guild_id`. Commands missing from `definitions` are deleted by Discord.
+pub async fn execute_commands_sync<C: RestClient>(
+    client: &C,
+    application_id: ApplicationId,
+    guild_id: Option<GuildId>,
+    definitions: &[CreateCommand],
+) -> Result<Vec<ApplicationCommand>, ClientError> {
+    let synced = client
+        .bulk_overwrite_application_commands(application_id, guild_id, definitions)
+        .await?;
+
+    for command in &synced {
+        println!("  {} ({})", command, command.id);
+    }
+
+    match guild_id {
+        Some(guild_id) => println!("✓ Synced {} commands to guild {}", synced.len(), guild_id),
+        None => println!("✓ Synced {} global commands", synced.len()),
+    }
+
+    Ok(synced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::testing::MockRestClient;
+    use reqwest::Method;
+
+    #[tokio::test]
+    async fn test_sync_overwrites_guild_commands() {
+        let client = MockRestClient::new();
+        let definitions = [
+            CreateCommand::chat_input("ping", "Check latency"),
+            CreateCommand::chat_input("deploy", "Deploy a service"),
+        ];
+
+        let synced =
+            execute_commands_sync(&client, ApplicationId(5), Some(GuildId(10)), &definitions)
+                .await
+                .unwrap();
+
+        assert_eq!(synced.len(), 2);
+        assert_eq!(client.application_commands(Some(GuildId(10))), synced);
+        assert_eq!(client.calls()[0].method, Method::PUT);
+        assert_eq!(client.calls()[0].path, "/applications/5/guilds/10/commands");
+    }
+
+    #[tokio::test]
+    async fn test_sync_rejects_invalid_definitions() {
+        let client = MockRestClient::new();
+        let definitions = [CreateCommand::chat_input("Ping", "Check latency")];
+
+        let result = execute_commands_sync(&client, ApplicationId(5), None, &definitions).await;
+
+        assert!(matches!(result, Err(ClientError::InvalidCommand(_))));
+        assert!(client.calls().is_empty());
+    }
+}
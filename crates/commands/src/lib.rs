@@ -1 +1,2 @@
-
+pub mod application_commands;
+pub mod webhook;
@@ -0,0 +1,123 @@
+use client::{
+    bodies::{EditWebhookMessage, ExecuteWebhook},
+    errors::client::ClientError,
+    webhook::WebhookClient,
+};
+use types::{
+    channel::ChannelId,
+    embed::Embed,
+    message::{Message, MessageId},
+};
+
+/// A message to post through a webhook, as given on the command line.
+#[derive(Debug, Default, Clone)]
+pub struct WebhookMessage {
+    pub content: Option<String>,
+    pub username: Option<String>,
+    pub avatar_url: Option<String>,
+    pub embed_title: Option<String>,
+    pub embed_description: Option<String>,
+    pub embed_color: Option<u32>,
+    pub thread_id: Option<ChannelId>,
+}
+
+impl WebhookMessage {
+    fn embed(&self) -> Option<Embed> {
+        if self.embed_title.is_none() && self.embed_description.is_none() {
+            return None;
+        }
+
+        Some(Embed {
+            title: self.embed_title.clone(),
+            description: self.embed_description.clone(),
+            color: self.embed_color,
+            ..Default::default()
+        })
+    }
+
+    fn to_body(&self) -> ExecuteWebhook {
+        ExecuteWebhook {
+            content: self.content.clone(),
+            username: self.username.clone(),
+            avatar_url: self.avatar_url.clone(),
+            embeds: self.embed().into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+pub async fn execute_webhook_send(
+    client: &WebhookClient,
+    message: &WebhookMessage,
+) -> Result<Message, ClientError> {
+    let sent = client
+        .execute(&message.to_body(), message.thread_id)
+        .await?;
+
+    println!("✓ Message {} sent", sent.id);
+    Ok(sent)
+}
+
+pub async fn execute_webhook_edit(
+    client: &WebhookClient,
+    message_id: MessageId,
+    content: &str,
+    thread_id: Option<ChannelId>,
+) -> Result<Message, ClientError> {
+    let edited = client
+        .edit_message(
+            message_id,
+            &EditWebhookMessage::with_content(content),
+            thread_id,
+        )
+        .await?;
+
+    println!("✓ Message {} edited", edited.id);
+    Ok(edited)
+}
+
+pub async fn execute_webhook_delete(
+    client: &WebhookClient,
+    message_id: MessageId,
+    thread_id: Option<ChannelId>,
+) -> Result<(), ClientError> {
+    client.delete_message(message_id, thread_id).await?;
+
+    println!("✓ Message {} deleted", message_id);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_body_without_embed() {
+        let message = WebhookMessage {
+            content: Some("Build passed".to_string()),
+            username: Some("CI".to_string()),
+            ..Default::default()
+        };
+
+        let body = message.to_body();
+
+        assert_eq!(body.content.as_deref(), Some("Build passed"));
+        assert_eq!(body.username.as_deref(), Some("CI"));
+        assert!(body.embeds.is_empty());
+    }
+
+    #[test]
+    fn test_body_with_embed() {
+        let message = WebhookMessage {
+            embed_title: Some("Deploy".to_string()),
+            embed_color: Some(0x2ecc71),
+            ..Default::default()
+        };
+
+        let body = message.to_body();
+
+        assert_eq!(body.embeds.len(), 1);
+        assert_eq!(body.embeds[0].title.as_deref(), Some("Deploy"));
+        assert_eq!(body.embeds[0].color, Some(0x2ecc71));
+    }
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AttachmentId(pub u64);
+
+impl fmt::Display for AttachmentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for AttachmentId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: AttachmentId,
+    pub filename: String,
+    pub size: u64,
+    pub url: String,
+    pub content_type: Option<String>,
+}
+
+impl fmt::Display for Attachment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({} bytes)", self.filename, self.size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_attachment_id_display() {
+        let id = AttachmentId(12345);
+        assert_eq!(format!("{}", id), "12345");
+    }
+
+    #[test]
+    fn test_attachment_id_from_u64() {
+        let id: AttachmentId = 12345.into();
+        assert_eq!(id, AttachmentId(12345));
+    }
+
+    #[test]
+    fn test_attachment_display() {
+        let attachment = Attachment {
+            id: AttachmentId(1),
+            filename: "build.log".to_string(),
+            size: 2048,
+            url: "https://cdn.discordapp.com/attachments/1/1/build.log".to_string(),
+            content_type: Some("text/plain".to_string()),
+        };
+        assert_eq!(format!("{}", attachment), "build.log (2048 bytes)");
+    }
+}
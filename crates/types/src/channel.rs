@@ -1,6 +1,9 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::{guild::GuildId, user::UserId};
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ChannelId(pub u64);
 
@@ -16,10 +19,119 @@ impl From<u64> for ChannelId {
     }
 }
 
+/// The kind of a channel as reported in its `type` field.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(from = "u8", into = "u8")]
+pub enum ChannelType {
+    #[default]
+    GuildText,
+    Dm,
+    GuildVoice,
+    GroupDm,
+    GuildCategory,
+    GuildAnnouncement,
+    AnnouncementThread,
+    PublicThread,
+    PrivateThread,
+    GuildStageVoice,
+    GuildDirectory,
+    GuildForum,
+    GuildMedia,
+    Unknown(u8),
+}
+
+impl ChannelType {
+    pub fn is_thread(self) -> bool {
+        matches!(
+            self,
+            ChannelType::AnnouncementThread
+                | ChannelType::PublicThread
+                | ChannelType::PrivateThread
+        )
+    }
+}
+
+impl From<u8> for ChannelType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ChannelType::GuildText,
+            1 => ChannelType::Dm,
+            2 => ChannelType::GuildVoice,
+            3 => ChannelType::GroupDm,
+            4 => ChannelType::GuildCategory,
+            5 => ChannelType::GuildAnnouncement,
+            10 => ChannelType::AnnouncementThread,
+            11 => ChannelType::PublicThread,
+            12 => ChannelType::PrivateThread,
+            13 => ChannelType::GuildStageVoice,
+            14 => ChannelType::GuildDirectory,
+            15 => ChannelType::GuildForum,
+            16 => ChannelType::GuildMedia,
+            other => ChannelType::Unknown(other),
+        }
+    }
+}
+
+impl From<ChannelType> for u8 {
+    fn from(value: ChannelType) -> Self {
+        match value {
+            ChannelType::GuildText => 0,
+            ChannelType::Dm => 1,
+            ChannelType::GuildVoice => 2,
+            ChannelType::GroupDm => 3,
+            ChannelType::GuildCategory => 4,
+            ChannelType::GuildAnnouncement => 5,
+            ChannelType::AnnouncementThread => 10,
+            ChannelType::PublicThread => 11,
+            ChannelType::PrivateThread => 12,
+            ChannelType::GuildStageVoice => 13,
+            ChannelType::GuildDirectory => 14,
+            ChannelType::GuildForum => 15,
+            ChannelType::GuildMedia => 16,
+            ChannelType::Unknown(other) => other,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Channel {
     pub id: ChannelId,
+    #[serde(rename = "type", default)]
+    pub kind: ChannelType,
     pub name: String,
+    pub guild_id: Option<GuildId>,
+    pub parent_id: Option<ChannelId>,
+    pub owner_id: Option<UserId>,
+    pub thread_metadata: Option<ThreadMetadata>,
+    pub message_count: Option<u32>,
+    pub member_count: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ThreadMetadata {
+    pub archived: bool,
+    pub auto_archive_duration: u32,
+    pub archive_timestamp: DateTime<Utc>,
+    pub locked: bool,
+    pub invitable: Option<bool>,
+    pub create_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadMember {
+    pub id: Option<ChannelId>,
+    pub user_id: Option<UserId>,
+    pub join_timestamp: DateTime<Utc>,
+    pub flags: u32,
+}
+
+/// A page of threads as returned by the active and archived thread listings.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ThreadList {
+    pub threads: Vec<Channel>,
+    pub members: Vec<ThreadMember>,
+    #[serde(default)]
+    pub has_more: bool,
 }
 
 impl fmt::Display for Channel {
@@ -48,8 +160,29 @@ mod tests {
     fn test_channel_display() {
         let channel = Channel {
             id: ChannelId(1),
+            kind: ChannelType::GuildText,
             name: "general".to_string(),
+            guild_id: None,
+            parent_id: None,
+            owner_id: None,
+            thread_metadata: None,
+            message_count: None,
+            member_count: None,
         };
         assert_eq!(format!("{}", channel), "#general");
     }
+
+    #[test]
+    fn test_channel_type_round_trip() {
+        for value in [0u8, 5, 10, 11, 12, 15, 99] {
+            assert_eq!(u8::from(ChannelType::from(value)), value);
+        }
+    }
+
+    #[test]
+    fn test_channel_type_is_thread() {
+        assert!(ChannelType::PublicThread.is_thread());
+        assert!(ChannelType::PrivateThread.is_thread());
+        assert!(!ChannelType::GuildText.is_thread());
+    }
 }
@@ -0,0 +1,263 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{channel::ChannelType, guild::GuildId};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ApplicationId(pub u64);
+
+impl fmt::Display for ApplicationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for ApplicationId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommandId(pub u64);
+
+impl fmt::Display for CommandId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for CommandId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+/// How a command is invoked: typed as a slash command, or picked from the
+/// context menu of a user or a message.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(from = "u8", into = "u8")]
+pub enum CommandType {
+    #[default]
+    ChatInput,
+    User,
+    Message,
+    Unknown(u8),
+}
+
+impl From<u8> for CommandType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => CommandType::ChatInput,
+            2 => CommandType::User,
+            3 => CommandType::Message,
+            other => CommandType::Unknown(other),
+        }
+    }
+}
+
+impl From<CommandType> for u8 {
+    fn from(value: CommandType) -> Self {
+        match value {
+            CommandType::ChatInput => 1,
+            CommandType::User => 2,
+            CommandType::Message => 3,
+            CommandType::Unknown(other) => other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum CommandOptionType {
+    SubCommand,
+    SubCommandGroup,
+    String,
+    Integer,
+    Boolean,
+    User,
+    Channel,
+    Role,
+    Mentionable,
+    Number,
+    Attachment,
+    Unknown(u8),
+}
+
+impl From<u8> for CommandOptionType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => CommandOptionType::SubCommand,
+            2 => CommandOptionType::SubCommandGroup,
+            3 => CommandOptionType::String,
+            4 => CommandOptionType::Integer,
+            5 => CommandOptionType::Boolean,
+            6 => CommandOptionType::User,
+            7 => CommandOptionType::Channel,
+            8 => CommandOptionType::Role,
+            9 => CommandOptionType::Mentionable,
+            10 => CommandOptionType::Number,
+            11 => CommandOptionType::Attachment,
+            other => CommandOptionType::Unknown(other),
+        }
+    }
+}
+
+impl From<CommandOptionType> for u8 {
+    fn from(value: CommandOptionType) -> Self {
+        match value {
+            CommandOptionType::SubCommand => 1,
+            CommandOptionType::SubCommandGroup => 2,
+            CommandOptionType::String => 3,
+            CommandOptionType::Integer => 4,
+            CommandOptionType::Boolean => 5,
+            CommandOptionType::User => 6,
+            CommandOptionType::Channel => 7,
+            CommandOptionType::Role => 8,
+            CommandOptionType::Mentionable => 9,
+            CommandOptionType::Number => 10,
+            CommandOptionType::Attachment => 11,
+            CommandOptionType::Unknown(other) => other,
+        }
+    }
+}
+
+/// A value a command option can take, either from a fixed choice or from the
+/// user's input.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum CommandOptionValue {
+    Boolean(bool),
+    Integer(i64),
+    Number(f64),
+    String(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommandOptionChoice {
+    pub name: String,
+    pub value: CommandOptionValue,
+}
+
+/// A parameter of a command. Sub-commands and groups nest further options.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommandOption {
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    pub name: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub required: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub choices: Vec<CommandOptionChoice>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<CommandOption>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_types: Vec<ChannelType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub autocomplete: bool,
+}
+
+impl CommandOption {
+    pub fn new(
+        kind: CommandOptionType,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            name: name.into(),
+            description: description.into(),
+            required: false,
+            choices: Vec::new(),
+            options: Vec::new(),
+            channel_types: Vec::new(),
+            min_value: None,
+            max_value: None,
+            min_length: None,
+            max_length: None,
+            autocomplete: false,
+        }
+    }
+
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn choice(mut self, name: impl Into<String>, value: CommandOptionValue) -> Self {
+        self.choices.push(CommandOptionChoice {
+            name: name.into(),
+            value,
+        });
+        self
+    }
+
+    /// Adds a nested option, for sub-commands and sub-command groups.
+    pub fn option(mut self, option: CommandOption) -> Self {
+        self.options.push(option);
+        self
+    }
+}
+
+/// A command registered for an application, globally or in one guild.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ApplicationCommand {
+    pub id: CommandId,
+    pub application_id: ApplicationId,
+    pub guild_id: Option<GuildId>,
+    #[serde(rename = "type", default)]
+    pub kind: CommandType,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub options: Vec<CommandOption>,
+    pub default_member_permissions: Option<String>,
+    #[serde(default)]
+    pub nsfw: bool,
+    pub version: CommandId,
+}
+
+impl fmt::Display for ApplicationCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            CommandType::ChatInput => write!(f, "/{}", self.name),
+            _ => write!(f, "{}", self.name),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_application_id_display() {
+        let id = ApplicationId(12345);
+        assert_eq!(format!("{}", id), "12345");
+    }
+
+    #[test]
+    fn test_command_id_from_u64() {
+        let id: CommandId = 12345.into();
+        assert_eq!(id, CommandId(12345));
+    }
+
+    #[test]
+    fn test_option_type_round_trips_through_u8() {
+        for value in 1..=12u8 {
+            assert_eq!(u8::from(CommandOptionType::from(value)), value);
+        }
+        assert_eq!(CommandOptionType::from(1), CommandOptionType::SubCommand);
+        assert_eq!(CommandOptionType::from(12), CommandOptionType::Unknown(12));
+    }
+}
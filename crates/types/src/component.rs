@@ -0,0 +1,256 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum ComponentType {
+    ActionRow,
+    Button,
+    StringSelect,
+    TextInput,
+    UserSelect,
+    RoleSelect,
+    MentionableSelect,
+    ChannelSelect,
+    Unknown(u8),
+}
+
+impl From<u8> for ComponentType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => ComponentType::ActionRow,
+            2 => ComponentType::Button,
+            3 => ComponentType::StringSelect,
+            4 => ComponentType::TextInput,
+            5 => ComponentType::UserSelect,
+            6 => ComponentType::RoleSelect,
+            7 => ComponentType::MentionableSelect,
+            8 => ComponentType::ChannelSelect,
+            other => ComponentType::Unknown(other),
+        }
+    }
+}
+
+impl From<ComponentType> for u8 {
+    fn from(value: ComponentType) -> Self {
+        match value {
+            ComponentType::ActionRow => 1,
+            ComponentType::Button => 2,
+            ComponentType::StringSelect => 3,
+            ComponentType::TextInput => 4,
+            ComponentType::UserSelect => 5,
+            ComponentType::RoleSelect => 6,
+            ComponentType::MentionableSelect => 7,
+            ComponentType::ChannelSelect => 8,
+            ComponentType::Unknown(other) => other,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum ButtonStyle {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Link,
+    Unknown(u8),
+}
+
+impl From<u8> for ButtonStyle {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => ButtonStyle::Primary,
+            2 => ButtonStyle::Secondary,
+            3 => ButtonStyle::Success,
+            4 => ButtonStyle::Danger,
+            5 => ButtonStyle::Link,
+            other => ButtonStyle::Unknown(other),
+        }
+    }
+}
+
+impl From<ButtonStyle> for u8 {
+    fn from(value: ButtonStyle) -> Self {
+        match value {
+            ButtonStyle::Primary => 1,
+            ButtonStyle::Secondary => 2,
+            ButtonStyle::Success => 3,
+            ButtonStyle::Danger => 4,
+            ButtonStyle::Link => 5,
+            ButtonStyle::Unknown(other) => other,
+        }
+    }
+}
+
+/// Whether a text input is a single line or a paragraph.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum TextInputStyle {
+    Short,
+    Paragraph,
+    Unknown(u8),
+}
+
+impl From<u8> for TextInputStyle {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => TextInputStyle::Short,
+            2 => TextInputStyle::Paragraph,
+            other => TextInputStyle::Unknown(other),
+        }
+    }
+}
+
+impl From<TextInputStyle> for u8 {
+    fn from(value: TextInputStyle) -> Self {
+        match value {
+            TextInputStyle::Short => 1,
+            TextInputStyle::Paragraph => 2,
+            TextInputStyle::Unknown(other) => other,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SelectOption {
+    pub label: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub default: bool,
+}
+
+/// A message or modal component. Action rows hold the other kinds in
+/// `components`; which of the remaining fields apply depends on `kind`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Component {
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+    /// A button's style; text inputs use `text_input_style` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub style: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub disabled: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<SelectOption>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub placeholder: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_values: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_values: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required: Option<bool>,
+    /// A text input's prefilled value, or its submitted value in a modal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<Component>,
+}
+
+impl Component {
+    fn new(kind: ComponentType) -> Self {
+        Self {
+            kind,
+            custom_id: None,
+            style: None,
+            label: None,
+            url: None,
+            disabled: false,
+            options: Vec::new(),
+            placeholder: None,
+            min_values: None,
+            max_values: None,
+            min_length: None,
+            max_length: None,
+            required: None,
+            value: None,
+            components: Vec::new(),
+        }
+    }
+
+    pub fn action_row(components: Vec<Component>) -> Self {
+        Self {
+            components,
+            ..Self::new(ComponentType::ActionRow)
+        }
+    }
+
+    pub fn button(
+        style: ButtonStyle,
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            style: Some(style.into()),
+            custom_id: Some(custom_id.into()),
+            label: Some(label.into()),
+            ..Self::new(ComponentType::Button)
+        }
+    }
+
+    pub fn link_button(url: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            style: Some(ButtonStyle::Link.into()),
+            url: Some(url.into()),
+            label: Some(label.into()),
+            ..Self::new(ComponentType::Button)
+        }
+    }
+
+    pub fn string_select(custom_id: impl Into<String>, options: Vec<SelectOption>) -> Self {
+        Self {
+            custom_id: Some(custom_id.into()),
+            options,
+            ..Self::new(ComponentType::StringSelect)
+        }
+    }
+
+    /// A text input for a modal. Like every modal component, it has to be
+    /// wrapped in an action row.
+    pub fn text_input(
+        style: TextInputStyle,
+        custom_id: impl Into<String>,
+        label: impl Into<String>,
+    ) -> Self {
+        Self {
+            style: Some(style.into()),
+            custom_id: Some(custom_id.into()),
+            label: Some(label.into()),
+            ..Self::new(ComponentType::TextInput)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component_type_from_u8() {
+        assert_eq!(ComponentType::from(2), ComponentType::Button);
+        assert_eq!(ComponentType::from(42), ComponentType::Unknown(42));
+    }
+
+    #[test]
+    fn test_button_style_round_trips_through_u8() {
+        let button = Component::button(ButtonStyle::Danger, "delete", "Delete");
+
+        assert_eq!(
+            button.style.map(ButtonStyle::from),
+            Some(ButtonStyle::Danger)
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct Embed {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub inline: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmbedFooter {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmbedAuthor {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmbedImage {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+}
+
+impl Embed {
+    /// Counts the characters Discord includes in its 6000-character embed
+    /// limit: title, description, field names and values, footer text and
+    /// author name.
+    pub fn char_count(&self) -> usize {
+        let len = |s: &Option<String>| s.as_deref().map_or(0, |s| s.chars().count());
+
+        len(&self.title)
+            + len(&self.description)
+            + self
+                .fields
+                .iter()
+                .map(|f| f.name.chars().count() + f.value.chars().count())
+                .sum::<usize>()
+            + self.footer.as_ref().map_or(0, |f| f.text.chars().count())
+            + self.author.as_ref().map_or(0, |a| a.name.chars().count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embed_char_count() {
+        let embed = Embed {
+            title: Some("Build".to_string()),
+            description: Some("passed".to_string()),
+            fields: vec![EmbedField {
+                name: "branch".to_string(),
+                value: "main".to_string(),
+                inline: true,
+            }],
+            footer: Some(EmbedFooter {
+                text: "ci".to_string(),
+                icon_url: None,
+            }),
+            author: Some(EmbedAuthor {
+                name: "bot".to_string(),
+                url: None,
+                icon_url: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(embed.char_count(), 5 + 6 + 6 + 4 + 2 + 3);
+    }
+
+    #[test]
+    fn test_embed_char_count_ignores_urls() {
+        let embed = Embed {
+            url: Some("https://example.com".to_string()),
+            image: Some(EmbedImage {
+                url: "https://example.com/a.png".to_string(),
+                height: None,
+                width: None,
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(embed.char_count(), 0);
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EmojiId(pub u64);
+
+impl fmt::Display for EmojiId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for EmojiId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Emoji {
+    pub id: Option<EmojiId>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub animated: bool,
+}
+
+/// An emoji as used to react to a message: either a unicode emoji or a
+/// custom guild emoji identified by `name:id`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReactionType {
+    Unicode(String),
+    Custom { name: String, id: EmojiId },
+}
+
+impl fmt::Display for ReactionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReactionType::Unicode(emoji) => write!(f, "{}", emoji),
+            ReactionType::Custom { name, id } => write!(f, "{}:{}", name, id),
+        }
+    }
+}
+
+impl From<&str> for ReactionType {
+    fn from(emoji: &str) -> Self {
+        let trimmed = emoji.trim_start_matches('<').trim_end_matches('>');
+
+        if let Some((name, id)) = trimmed.rsplit_once(':')
+            && let Ok(id) = id.parse::<u64>()
+        {
+            let name = name.trim_start_matches("a:").trim_start_matches(':');
+            return ReactionType::Custom {
+                name: name.to_string(),
+                id: EmojiId(id),
+            };
+        }
+
+        ReactionType::Unicode(emoji.to_string())
+    }
+}
+
+impl From<Emoji> for ReactionType {
+    fn from(emoji: Emoji) -> Self {
+        match (emoji.id, emoji.name) {
+            (Some(id), name) => ReactionType::Custom {
+                name: name.unwrap_or_default(),
+                id,
+            },
+            (None, name) => ReactionType::Unicode(name.unwrap_or_default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_emoji_id_display() {
+        let id = EmojiId(12345);
+        assert_eq!(format!("{}", id), "12345");
+    }
+
+    #[test]
+    fn test_reaction_type_from_unicode() {
+        let reaction = ReactionType::from("👍");
+        assert_eq!(reaction, ReactionType::Unicode("👍".to_string()));
+    }
+
+    #[test]
+    fn test_reaction_type_from_custom() {
+        let expected = ReactionType::Custom {
+            name: "shipit".to_string(),
+            id: EmojiId(42),
+        };
+
+        assert_eq!(ReactionType::from("shipit:42"), expected);
+        assert_eq!(ReactionType::from("<:shipit:42>"), expected);
+        assert_eq!(ReactionType::from("<a:shipit:42>"), expected);
+    }
+
+    #[test]
+    fn test_reaction_type_display() {
+        let reaction = ReactionType::Custom {
+            name: "shipit".to_string(),
+            id: EmojiId(42),
+        };
+        assert_eq!(format!("{}", reaction), "shipit:42");
+    }
+}
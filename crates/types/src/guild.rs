@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+use crate::user::UserId;
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct GuildId(pub u64);
 
@@ -16,11 +18,39 @@ impl From<u64> for GuildId {
     }
 }
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RoleId(pub u64);
+
+impl fmt::Display for RoleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for RoleId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+/// A guild. `/users/@me/guilds` returns partial guilds, so everything past
+/// the name may be missing; `owner` and `permissions` are only set there.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Guild {
     pub id: GuildId,
     pub name: String,
     pub description: Option<String>,
+    pub icon: Option<String>,
+    pub owner_id: Option<UserId>,
+    /// Whether the current user owns the guild.
+    #[serde(default)]
+    pub owner: bool,
+    /// The current user's permissions in the guild, as a bitfield string.
+    pub permissions: Option<String>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    pub approximate_member_count: Option<u64>,
+    pub approximate_presence_count: Option<u64>,
 }
 
 impl fmt::Display for Guild {
@@ -45,12 +75,25 @@ mod tests {
         assert_eq!(id, GuildId(12345));
     }
 
+    #[test]
+    fn test_role_id_display() {
+        let id = RoleId(12345);
+        assert_eq!(format!("{}", id), "12345");
+    }
+
     #[test]
     fn test_guild_display() {
         let guild = Guild {
             id: GuildId(1),
             name: "Test Guild".to_string(),
             description: None,
+            icon: None,
+            owner_id: None,
+            owner: false,
+            permissions: None,
+            features: Vec::new(),
+            approximate_member_count: None,
+            approximate_presence_count: None,
         };
         assert_eq!(format!("{}", guild), "Test Guild");
     }
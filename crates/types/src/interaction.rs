@@ -0,0 +1,227 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::{
+    channel::ChannelId,
+    command::{ApplicationId, CommandId, CommandOptionType, CommandOptionValue, CommandType},
+    component::{Component, ComponentType},
+    guild::{GuildId, RoleId},
+    message::Message,
+    user::User,
+};
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct InteractionId(pub u64);
+
+impl fmt::Display for InteractionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<u64> for InteractionId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum InteractionType {
+    Ping,
+    ApplicationCommand,
+    MessageComponent,
+    ApplicationCommandAutocomplete,
+    ModalSubmit,
+    Unknown(u8),
+}
+
+impl From<u8> for InteractionType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => InteractionType::Ping,
+            2 => InteractionType::ApplicationCommand,
+            3 => InteractionType::MessageComponent,
+            4 => InteractionType::ApplicationCommandAutocomplete,
+            5 => InteractionType::ModalSubmit,
+            other => InteractionType::Unknown(other),
+        }
+    }
+}
+
+impl From<InteractionType> for u8 {
+    fn from(value: InteractionType) -> Self {
+        match value {
+            InteractionType::Ping => 1,
+            InteractionType::ApplicationCommand => 2,
+            InteractionType::MessageComponent => 3,
+            InteractionType::ApplicationCommandAutocomplete => 4,
+            InteractionType::ModalSubmit => 5,
+            InteractionType::Unknown(other) => other,
+        }
+    }
+}
+
+/// The member who triggered an interaction in a guild.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InteractionMember {
+    pub user: User,
+    pub nick: Option<String>,
+    #[serde(default)]
+    pub roles: Vec<RoleId>,
+    /// The member's permissions in the channel, as a bitfield string.
+    pub permissions: Option<String>,
+}
+
+/// An option the user filled in, or a sub-command with its own options.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommandDataOption {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    pub value: Option<CommandOptionValue>,
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    /// Set on the option being typed in an autocomplete interaction.
+    #[serde(default)]
+    pub focused: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CommandData {
+    pub id: CommandId,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: CommandType,
+    #[serde(default)]
+    pub options: Vec<CommandDataOption>,
+    pub guild_id: Option<GuildId>,
+    /// The user or message a context-menu command was used on.
+    pub target_id: Option<u64>,
+}
+
+impl CommandData {
+    /// The value of the top-level option `name`, if the user gave one.
+    pub fn option(&self, name: &str) -> Option<&CommandOptionValue> {
+        self.options
+            .iter()
+            .find(|o| o.name == name)
+            .and_then(|o| o.value.as_ref())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ComponentData {
+    pub custom_id: String,
+    pub component_type: ComponentType,
+    /// The selected values, for select menus.
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModalSubmitData {
+    pub custom_id: String,
+    pub components: Vec<Component>,
+}
+
+impl ModalSubmitData {
+    /// The submitted value of the text input `custom_id`.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| row.components.iter().chain(std::iter::once(row)))
+            .find(|c| c.custom_id.as_deref() == Some(custom_id))
+            .and_then(|c| c.value.as_deref())
+    }
+}
+
+/// The payload of an interaction. Which variant is present follows from the
+/// interaction's type: commands and autocomplete carry `Command`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum InteractionData {
+    Command(CommandData),
+    Component(ComponentData),
+    ModalSubmit(ModalSubmitData),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Interaction {
+    pub id: InteractionId,
+    pub application_id: ApplicationId,
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    pub data: Option<InteractionData>,
+    pub guild_id: Option<GuildId>,
+    pub channel_id: Option<ChannelId>,
+    /// Set for interactions in a guild.
+    pub member: Option<InteractionMember>,
+    /// Set for interactions in a DM.
+    pub user: Option<User>,
+    /// Authorises the response and follow-ups for 15 minutes.
+    pub token: String,
+    /// The message a component was attached to.
+    pub message: Option<Message>,
+    pub locale: Option<String>,
+}
+
+impl Interaction {
+    /// The user who triggered the interaction, in a guild or a DM.
+    pub fn author(&self) -> Option<&User> {
+        self.member.as_ref().map(|m| &m.user).or(self.user.as_ref())
+    }
+
+    pub fn command_data(&self) -> Option<&CommandData> {
+        match &self.data {
+            Some(InteractionData::Command(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub fn component_data(&self) -> Option<&ComponentData> {
+        match &self.data {
+            Some(InteractionData::Component(data)) => Some(data),
+            _ => None,
+        }
+    }
+
+    pub fn modal_data(&self) -> Option<&ModalSubmitData> {
+        match &self.data {
+            Some(InteractionData::ModalSubmit(data)) => Some(data),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::component::TextInputStyle;
+
+    #[test]
+    fn test_interaction_id_display() {
+        let id = InteractionId(12345);
+        assert_eq!(format!("{}", id), "12345");
+    }
+
+    #[test]
+    fn test_interaction_id_from_u64() {
+        let id: InteractionId = 12345.into();
+        assert_eq!(id, InteractionId(12345));
+    }
+
+    #[test]
+    fn test_modal_value_searches_action_rows() {
+        let mut input = Component::text_input(TextInputStyle::Paragraph, "reason", "Reason");
+        input.value = Some("spam".to_string());
+
+        let data = ModalSubmitData {
+            custom_id: "report".to_string(),
+            components: vec![Component::action_row(vec![input])],
+        };
+
+        assert_eq!(data.value("reason"), Some("spam"));
+        assert_eq!(data.value("missing"), None);
+    }
+}
@@ -1,4 +1,11 @@
+pub mod attachment;
 pub mod channel;
+pub mod command;
+pub mod component;
+pub mod embed;
+pub mod emoji;
 pub mod guild;
+pub mod interaction;
 pub mod message;
 pub mod user;
+pub mod webhook;
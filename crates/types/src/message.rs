@@ -2,9 +2,12 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-use crate::{channel::ChannelId, user::User};
+use crate::{
+    attachment::Attachment, channel::ChannelId, embed::Embed, emoji::Emoji, guild::GuildId,
+    user::User,
+};
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct MessageId(pub u64);
 
 impl fmt::Display for MessageId {
@@ -26,6 +29,45 @@ pub struct Message {
     pub content: String,
     pub channel_id: ChannelId,
     pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub reactions: Vec<Reaction>,
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    #[serde(default)]
+    pub pinned: bool,
+    pub message_reference: Option<MessageReference>,
+    pub referenced_message: Option<Box<Message>>,
+}
+
+/// Points at the message a reply (or crosspost) refers to.
+#[derive(Clone, Debug, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct MessageReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_id: Option<MessageId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel_id: Option<ChannelId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub guild_id: Option<GuildId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+}
+
+impl MessageReference {
+    pub fn to_message(message_id: MessageId) -> Self {
+        Self {
+            message_id: Some(message_id),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Reaction {
+    pub count: u32,
+    pub me: bool,
+    pub emoji: Emoji,
 }
 
 impl fmt::Display for Message {
@@ -74,6 +116,12 @@ mod tests {
             content: "Hello, world!".to_string(),
             channel_id: ChannelId(1),
             timestamp,
+            reactions: Vec::new(),
+            attachments: Vec::new(),
+            embeds: Vec::new(),
+            pinned: false,
+            message_reference: None,
+            referenced_message: None,
         };
         assert_eq!(
             format!("{}", message),
@@ -19,6 +19,7 @@ impl From<u64> for UserId {
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct User {
     pub id: UserId,
+    #[serde(default)]
     pub email: String,
     pub username: String,
     pub discriminator: String,